@@ -7,7 +7,7 @@ use rand::random_ratio;
 use serde::Deserialize;
 use std::time::Duration;
 
-// 2025-06-05T19:52:47.728Z INFO  [env] Config: Config { backoff: Fibonacci(FibonacciBackoffConfig { initial_delay: 100ms, max_delay: 30s, max_retries: 8, jitter_enabled: true, jitter_seed: None }) }
+// 2025-06-05T19:52:47.728Z INFO  [env] Backoff: fibonacci 100ms up to 30s, 8 retries, jitter
 // 2025-06-05T19:52:47.728Z INFO  [env] Doing very important work
 // 2025-06-05T19:52:48.234Z WARN  [env] Failure: 'Very important error', sleeping 152ms
 // 2025-06-05T19:52:48.393Z INFO  [env] Doing very important work
@@ -30,7 +30,7 @@ async fn main() -> anyhow::Result<()> {
         .merge(Env::prefixed("CONFIG__").split("__"))
         .extract()?;
 
-    info!("Config: {config:?}");
+    info!("Backoff: {}", config.backoff);
 
     // Use it in retries.
     may_fail
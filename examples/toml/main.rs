@@ -7,7 +7,7 @@ use rand::random_ratio;
 use serde::Deserialize;
 use std::time::Duration;
 
-// 2025-06-05T19:53:29.048Z INFO  [toml] Config: Config { backoff: Exponential(ExponentialBackoffConfig { initial_delay: 100ms, factor: 2.0, max_delay: 30s, max_retries: 8, max_total_delay: 60s, jitter_enabled: true, jitter_seed: None }) }
+// 2025-06-05T19:53:29.048Z INFO  [toml] Backoff: exponential 100ms×2.0 up to 30s, 8 retries, ≤60s total, jitter
 // 2025-06-05T19:53:29.048Z INFO  [toml] Doing very important work
 // 2025-06-05T19:53:29.555Z WARN  [toml] Failure: 'Very important error', sleeping 123ms
 // 2025-06-05T19:53:29.682Z INFO  [toml] Doing very important work
@@ -27,7 +27,7 @@ async fn main() -> anyhow::Result<()> {
         .merge(Data::<Toml>::file("examples/toml/config.toml"))
         .extract()?;
 
-    info!("Config: {config:?}");
+    info!("Backoff: {}", config.backoff);
 
     // Use it in retries.
     may_fail
@@ -0,0 +1,265 @@
+use crate::*;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::Duration;
+
+#[derive(Debug, Clone)]
+/// Shared state of [Backoff::Adaptive] backoffs, holding the current base delay.
+///
+/// Cloning the state is cheap and all clones share the same base delay. Callers report the outcome of each operation
+/// via [AdaptiveState::record_failure] and [AdaptiveState::record_success], so the base delay grows while operations
+/// keep failing and shrinks back towards [AdaptiveBackoffConfig::min_delay] once they succeed.
+///
+/// Every [Backoff] built from the state starts at the current base delay and grows it by
+/// [AdaptiveBackoffConfig::increase_factor] for its own retries only. Iterating a [Backoff] never changes the state, so
+/// previewing a schedule doesn't affect other users.
+pub struct AdaptiveState {
+    config: AdaptiveBackoffConfig,
+    base_delay_nanos: Arc<AtomicU64>,
+}
+
+impl AdaptiveState {
+    /// Creates a new [AdaptiveState] starting at [AdaptiveBackoffConfig::initial_delay].
+    pub fn new(config: AdaptiveBackoffConfig) -> AdaptiveState {
+        let initial_delay = config
+            .initial_delay
+            .clamp(config.min_delay, config.max_delay.max(config.min_delay));
+
+        AdaptiveState {
+            config,
+            base_delay_nanos: Arc::new(AtomicU64::new(as_nanos(initial_delay))),
+        }
+    }
+
+    /// Returns the [AdaptiveBackoffConfig] of this state.
+    pub fn config(&self) -> AdaptiveBackoffConfig {
+        self.config
+    }
+
+    /// Returns the current base delay, without jitter.
+    pub fn base_delay(&self) -> Duration {
+        Duration::from_nanos(self.base_delay_nanos.load(Ordering::Acquire))
+    }
+
+    /// Records a failure, multiplying the base delay by [AdaptiveBackoffConfig::increase_factor].
+    ///
+    /// Returns the base delay before the increase.
+    pub fn record_failure(&self) -> Duration {
+        self.update(|delay| increase(&self.config, delay))
+    }
+
+    /// Records a success, decreasing the base delay by [AdaptiveBackoffConfig::decrease_step].
+    ///
+    /// Returns the base delay before the decrease.
+    pub fn record_success(&self) -> Duration {
+        let AdaptiveBackoffConfig {
+            decrease_step,
+            min_delay,
+            ..
+        } = self.config;
+
+        self.update(|delay| delay.saturating_sub(decrease_step).max(min_delay))
+    }
+
+    /// Creates a new [Backoff::Adaptive] that reads and updates this state.
+    pub fn backoff(&self) -> Backoff {
+        Backoff::Adaptive(AdaptiveBackoff {
+            state: self.clone(),
+            attempts: 0,
+            delay: self.base_delay(),
+            cumulative_delay: Duration::ZERO,
+            rng: crate::backoff::rng(self.config.jitter.seed()),
+        })
+    }
+
+    fn update(&self, f: impl Fn(Duration) -> Duration) -> Duration {
+        let previous = self
+            .base_delay_nanos
+            .fetch_update(Ordering::AcqRel, Ordering::Acquire, |nanos| {
+                Some(as_nanos(f(Duration::from_nanos(nanos))))
+            })
+            .unwrap_or_else(|nanos| nanos);
+
+        Duration::from_nanos(previous)
+    }
+}
+
+impl backon::BackoffBuilder for AdaptiveState {
+    type Backoff = Backoff;
+
+    fn build(self) -> Backoff {
+        self.backoff()
+    }
+}
+
+impl backon::BackoffBuilder for &AdaptiveState {
+    type Backoff = Backoff;
+
+    fn build(self) -> Backoff {
+        self.backoff()
+    }
+}
+
+/// Multiplies `delay` by [AdaptiveBackoffConfig::increase_factor], staying within the configured delays.
+fn increase(config: &AdaptiveBackoffConfig, delay: Duration) -> Duration {
+    Duration::try_from_secs_f64(delay.as_secs_f64() * f64::from(config.increase_factor))
+        .unwrap_or(Duration::MAX)
+        .clamp(config.min_delay, config.max_delay.max(config.min_delay))
+}
+
+fn as_nanos(duration: Duration) -> u64 {
+    u64::try_from(duration.as_nanos()).unwrap_or(u64::MAX)
+}
+
+#[derive(Debug, Clone)]
+/// Backoff that starts at the base delay of an [AdaptiveState] - see [Backoff::Adaptive].
+pub struct AdaptiveBackoff {
+    state: AdaptiveState,
+    attempts: usize,
+    delay: Duration,
+    cumulative_delay: Duration,
+    rng: fastrand::Rng,
+}
+
+impl AdaptiveBackoff {
+    /// Returns the shared [AdaptiveState] of this backoff.
+    pub fn state(&self) -> &AdaptiveState {
+        &self.state
+    }
+
+    /// Resumes from `snapshot` with a new [AdaptiveState] starting at the snapshotted delay.
+    pub(crate) fn resume(
+        config: AdaptiveBackoffConfig,
+        snapshot: &BackoffSnapshot,
+    ) -> AdaptiveBackoff {
+        let state = AdaptiveState::new(config);
+        if let Some(base_delay) = snapshot.current_delay {
+            state.update(|_| base_delay);
+        }
+
+        AdaptiveBackoff {
+            attempts: snapshot.attempts,
+            delay: state.base_delay(),
+            state,
+            cumulative_delay: snapshot.cumulative_delay,
+            rng: fastrand::Rng::with_seed(snapshot.rng_state),
+        }
+    }
+
+    pub(crate) fn snapshot(&self) -> BackoffSnapshot {
+        BackoffSnapshot {
+            attempts: self.attempts,
+            current_delay: Some(self.delay),
+            previous_delay: None,
+            cumulative_delay: self.cumulative_delay,
+            rng_state: self.rng.get_seed(),
+        }
+    }
+}
+
+impl Iterator for AdaptiveBackoff {
+    type Item = Duration;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.attempts >= self.state.config.max_retries {
+            return None;
+        }
+        self.attempts += 1;
+
+        let base = self.delay;
+        self.delay = increase(&self.state.config, base);
+
+        let delay = if self.state.config.jitter.is_enabled() {
+            crate::backoff::jitter(base, base, &mut self.rng)
+        } else {
+            base
+        };
+
+        self.cumulative_delay = self.cumulative_delay.saturating_add(delay);
+        Some(delay)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config() -> AdaptiveBackoffConfig {
+        AdaptiveBackoffConfig {
+            initial_delay: Duration::from_millis(400),
+            min_delay: Duration::from_millis(100),
+            max_delay: Duration::from_millis(1000),
+            increase_factor: 2_f32,
+            decrease_step: Duration::from_millis(250),
+            max_retries: 3,
+            jitter: Jitter::Disabled,
+        }
+    }
+
+    #[test]
+    fn increases_multiplicatively_and_decreases_additively() {
+        let state = AdaptiveState::new(config());
+
+        assert_eq!(state.record_failure(), Duration::from_millis(400));
+        assert_eq!(state.record_failure(), Duration::from_millis(800));
+        assert_eq!(state.base_delay(), Duration::from_millis(1000));
+
+        assert_eq!(state.record_success(), Duration::from_millis(1000));
+        assert_eq!(state.record_success(), Duration::from_millis(750));
+        assert_eq!(state.record_success(), Duration::from_millis(500));
+        assert_eq!(state.record_success(), Duration::from_millis(250));
+        assert_eq!(state.base_delay(), Duration::from_millis(100));
+    }
+
+    #[test]
+    fn backoffs_start_at_shared_base_delay() {
+        let state = AdaptiveState::new(config());
+
+        assert_eq!(
+            state.backoff().map(|d| d.as_millis()).collect::<Vec<_>>(),
+            vec![400, 800, 1000]
+        );
+
+        state.record_failure();
+        state.clone().record_success();
+
+        assert_eq!(
+            state.backoff().map(|d| d.as_millis()).collect::<Vec<_>>(),
+            vec![550, 1000, 1000]
+        );
+    }
+
+    #[test]
+    fn backoffs_do_not_change_state() {
+        let state = AdaptiveState::new(config());
+
+        state.backoff().for_each(drop);
+        state.backoff().clone().for_each(drop);
+        BackoffConfig::Adaptive(config()).simulate(0);
+
+        assert_eq!(state.base_delay(), Duration::from_millis(400));
+    }
+
+    #[test]
+    fn clamps_initial_delay() {
+        let state = AdaptiveState::new(AdaptiveBackoffConfig {
+            initial_delay: Duration::from_secs(5),
+            ..config()
+        });
+
+        assert_eq!(state.base_delay(), Duration::from_millis(1000));
+    }
+
+    #[test]
+    fn is_deterministic_with_jitter_seed() {
+        let config = AdaptiveBackoffConfig {
+            jitter: Jitter::Seeded(42),
+            ..config()
+        };
+
+        let first = AdaptiveState::new(config).backoff().collect::<Vec<_>>();
+        let second = AdaptiveState::new(config).backoff().collect::<Vec<_>>();
+
+        assert_eq!(first, second);
+    }
+}
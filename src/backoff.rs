@@ -1,8 +1,11 @@
-use backon::*;
+use crate::*;
 use std::time::Duration;
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 /// Supported backoffs.
+///
+/// Cloning a [Backoff] copies its progress, so the clone yields the same delays as the original. Clones of
+/// [Backoff::Adaptive] share the same [AdaptiveState].
 pub enum Backoff {
     /// Constant backoff.
     Constant(ConstantBackoff),
@@ -13,10 +16,67 @@ pub enum Backoff {
     /// Fibonacci backoff.
     Fibonacci(FibonacciBackoff),
 
+    /// Adaptive (AIMD) backoff.
+    Adaptive(AdaptiveBackoff),
+
+    /// Randomized exponential backoff.
+    RandomizedExponential(RandomizedExponentialBackoff),
+
+    /// Polynomial backoff.
+    Polynomial(PolynomialBackoff),
+
+    /// Uniform random backoff.
+    Random(RandomBackoff),
+
     /// No backoff.
     NoBackoff,
 }
 
+impl Backoff {
+    /// Returns the [BackoffConfig] this [Backoff] was built from.
+    pub fn config(&self) -> BackoffConfig {
+        match self {
+            Backoff::Constant(c) => BackoffConfig::Constant(c.config),
+            Backoff::Exponential(e) => BackoffConfig::Exponential(e.config),
+            Backoff::Fibonacci(f) => BackoffConfig::Fibonacci(f.config),
+            Backoff::Adaptive(a) => BackoffConfig::Adaptive(a.state().config()),
+            Backoff::RandomizedExponential(r) => BackoffConfig::RandomizedExponential(r.config),
+            Backoff::Polynomial(p) => BackoffConfig::Polynomial(p.config),
+            Backoff::Random(r) => BackoffConfig::Random(r.config),
+            Backoff::NoBackoff => BackoffConfig::NoBackoff,
+        }
+    }
+
+    /// Returns the amount of delays requested so far, i.e. the index of the next retry starting at `0`.
+    pub fn attempt(&self) -> usize {
+        self.snapshot().attempts
+    }
+
+    /// Returns the amount of retries left until the maximum amount of retries is reached.
+    ///
+    /// [Backoff::Exponential] and [Backoff::RandomizedExponential] may stop earlier due to their maximum total delay -
+    /// see [Iterator::size_hint].
+    pub fn remaining_retries(&self) -> usize {
+        self.config().max_retries().saturating_sub(self.attempt())
+    }
+
+    /// Returns the sum of all delays yielded so far, including jitter.
+    pub fn cumulative_delay(&self) -> Duration {
+        self.snapshot().cumulative_delay
+    }
+
+    /// Restarts this [Backoff] from the first retry.
+    ///
+    /// With a jitter seed, the same delays are yielded again. [Backoff::Adaptive] keeps its shared [AdaptiveState], so
+    /// it restarts from the current base delay.
+    pub fn reset(&mut self) {
+        *self = match self {
+            Backoff::Adaptive(a) => a.state().backoff(),
+            _ => backon::BackoffBuilder::build(self.config()),
+        };
+    }
+}
+
 impl Iterator for Backoff {
     type Item = Duration;
 
@@ -25,7 +85,976 @@ impl Iterator for Backoff {
             Backoff::Constant(c) => c.next(),
             Backoff::Exponential(e) => e.next(),
             Backoff::Fibonacci(f) => f.next(),
+            Backoff::Adaptive(a) => a.next(),
+            Backoff::RandomizedExponential(r) => r.next(),
+            Backoff::Polynomial(p) => p.next(),
+            Backoff::Random(r) => r.next(),
             Backoff::NoBackoff => None,
         }
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.remaining_retries();
+
+        match self {
+            Backoff::Exponential(e) => (e.guaranteed_retries().min(remaining), Some(remaining)),
+            Backoff::RandomizedExponential(r) => {
+                (r.guaranteed_retries().min(remaining), Some(remaining))
+            }
+            _ => (remaining, Some(remaining)),
+        }
+    }
+}
+
+/// Creates the jitter random number generator, seeded by `seed` if given.
+pub(crate) fn rng(seed: Option<u64>) -> fastrand::Rng {
+    match seed {
+        Some(seed) => fastrand::Rng::with_seed(seed),
+        None => fastrand::Rng::new(),
+    }
+}
+
+/// Adds a random jitter of up to `base` to `delay`, the same way [backon] does.
+pub(crate) fn jitter(delay: Duration, base: Duration, rng: &mut fastrand::Rng) -> Duration {
+    delay.saturating_add(base.mul_f32(rng.f32()))
+}
+
+/// Returns the largest jitter [jitter] adds for the given `base`.
+///
+/// [fastrand::Rng::f32] never returns `1.0`, and the product is rounded the same way as in [Duration::mul_f32].
+pub(crate) fn max_jitter(base: Duration) -> Duration {
+    base.mul_f32(1.0 - f32::EPSILON)
+}
+
+#[derive(Debug, Clone)]
+/// Backoff that always delays by the same [ConstantBackoffConfig::delay] - see [Backoff::Constant].
+///
+/// Yields exactly the same delays as [backon::ConstantBackoff].
+pub struct ConstantBackoff {
+    config: ConstantBackoffConfig,
+    attempts: usize,
+    cumulative_delay: Duration,
+    rng: fastrand::Rng,
+}
+
+impl ConstantBackoff {
+    pub(crate) fn new(config: ConstantBackoffConfig) -> ConstantBackoff {
+        ConstantBackoff {
+            config,
+            attempts: 0,
+            cumulative_delay: Duration::ZERO,
+            rng: rng(config.jitter.seed()),
+        }
+    }
+
+    pub(crate) fn resume(
+        config: ConstantBackoffConfig,
+        snapshot: &BackoffSnapshot,
+    ) -> ConstantBackoff {
+        ConstantBackoff {
+            config,
+            attempts: snapshot.attempts,
+            cumulative_delay: snapshot.cumulative_delay,
+            rng: fastrand::Rng::with_seed(snapshot.rng_state),
+        }
+    }
+
+    pub(crate) fn snapshot(&self) -> BackoffSnapshot {
+        BackoffSnapshot {
+            attempts: self.attempts,
+            current_delay: None,
+            previous_delay: None,
+            cumulative_delay: self.cumulative_delay,
+            rng_state: self.rng.get_seed(),
+        }
+    }
+}
+
+impl Iterator for ConstantBackoff {
+    type Item = Duration;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.attempts >= self.config.max_retries {
+            return None;
+        }
+        self.attempts += 1;
+
+        let delay = self.config.delay;
+        let delay = if self.config.jitter.is_enabled() {
+            jitter(delay, delay, &mut self.rng)
+        } else {
+            delay
+        };
+
+        self.cumulative_delay = self.cumulative_delay.saturating_add(delay);
+        Some(delay)
+    }
+}
+
+#[derive(Debug, Clone)]
+/// Backoff that multiplies the delay by [ExponentialBackoffConfig::factor] on every retry - see
+/// [Backoff::Exponential].
+///
+/// Yields exactly the same delays as [backon::ExponentialBackoff].
+pub struct ExponentialBackoff {
+    config: ExponentialBackoffConfig,
+    attempts: usize,
+    current_delay: Option<Duration>,
+    cumulative_delay: Duration,
+    rng: fastrand::Rng,
+}
+
+impl ExponentialBackoff {
+    pub(crate) fn new(config: ExponentialBackoffConfig) -> ExponentialBackoff {
+        ExponentialBackoff {
+            config,
+            attempts: 0,
+            current_delay: None,
+            cumulative_delay: Duration::ZERO,
+            rng: rng(config.jitter.seed()),
+        }
+    }
+
+    pub(crate) fn resume(
+        config: ExponentialBackoffConfig,
+        snapshot: &BackoffSnapshot,
+    ) -> ExponentialBackoff {
+        ExponentialBackoff {
+            config,
+            attempts: snapshot.attempts,
+            current_delay: snapshot.current_delay,
+            cumulative_delay: snapshot.cumulative_delay,
+            rng: fastrand::Rng::with_seed(snapshot.rng_state),
+        }
+    }
+
+    pub(crate) fn snapshot(&self) -> BackoffSnapshot {
+        BackoffSnapshot {
+            attempts: self.attempts,
+            current_delay: self.current_delay,
+            previous_delay: None,
+            cumulative_delay: self.cumulative_delay,
+            rng_state: self.rng.get_seed(),
+        }
+    }
+}
+
+impl ExponentialBackoff {
+    /// Returns the base delay of the retry following the one with the base delay `current`.
+    fn next_base(&self, current: Option<Duration>) -> Duration {
+        match current {
+            None => self.config.initial_delay,
+            Some(mut current) => {
+                if current < self.config.max_delay {
+                    current =
+                        Duration::try_from_secs_f32(self.config.factor * current.as_secs_f32())
+                            .unwrap_or(Duration::MAX);
+                }
+                current.min(self.config.max_delay)
+            }
+        }
+    }
+
+    /// Returns the amount of following retries that fit into the maximum total delay even with the largest jitter.
+    fn guaranteed_retries(&self) -> usize {
+        let worst_case = |base: Duration| {
+            if self.config.jitter.is_enabled() {
+                base.saturating_add(max_jitter(base))
+            } else {
+                base
+            }
+        };
+
+        let mut left = self.config.max_retries.saturating_sub(self.attempts);
+        let mut current = self.current_delay;
+        let mut cumulative = self.cumulative_delay;
+        let mut retries = 0;
+
+        while left > 0 {
+            let base = self.next_base(current);
+            let delay = worst_case(base);
+
+            // Once the base delay stops changing, the remaining retries can be counted at once.
+            if current == Some(base) {
+                let fitting = self
+                    .config
+                    .max_total_delay
+                    .saturating_sub(cumulative)
+                    .as_nanos()
+                    .checked_div(delay.as_nanos())
+                    .map_or(left, |fitting| fitting.min(left as u128) as usize);
+                return retries + fitting;
+            }
+
+            if cumulative.saturating_add(delay) > self.config.max_total_delay {
+                break;
+            }
+
+            cumulative = cumulative.saturating_add(delay);
+            current = Some(base);
+            retries += 1;
+            left -= 1;
+        }
+
+        retries
+    }
+}
+
+impl Iterator for ExponentialBackoff {
+    type Item = Duration;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.attempts >= self.config.max_retries {
+            return None;
+        }
+        self.attempts += 1;
+
+        let base = self.next_base(self.current_delay);
+
+        let delay = if self.config.jitter.is_enabled() {
+            jitter(base, base, &mut self.rng)
+        } else {
+            base
+        };
+
+        if self.cumulative_delay.saturating_add(delay) > self.config.max_total_delay {
+            return None;
+        }
+
+        self.cumulative_delay = self.cumulative_delay.saturating_add(delay);
+        self.current_delay = Some(base);
+        Some(delay)
+    }
+}
+
+#[derive(Debug, Clone)]
+/// Backoff that delays by the sum of the two previous delays - see [Backoff::Fibonacci].
+///
+/// Yields exactly the same delays as [backon::FibonacciBackoff].
+pub struct FibonacciBackoff {
+    config: FibonacciBackoffConfig,
+    attempts: usize,
+    current_delay: Option<Duration>,
+    previous_delay: Option<Duration>,
+    cumulative_delay: Duration,
+    rng: fastrand::Rng,
+}
+
+impl FibonacciBackoff {
+    pub(crate) fn new(config: FibonacciBackoffConfig) -> FibonacciBackoff {
+        FibonacciBackoff {
+            config,
+            attempts: 0,
+            current_delay: None,
+            previous_delay: None,
+            cumulative_delay: Duration::ZERO,
+            rng: rng(config.jitter.seed()),
+        }
+    }
+
+    pub(crate) fn resume(
+        config: FibonacciBackoffConfig,
+        snapshot: &BackoffSnapshot,
+    ) -> FibonacciBackoff {
+        FibonacciBackoff {
+            config,
+            attempts: snapshot.attempts,
+            current_delay: snapshot.current_delay,
+            previous_delay: snapshot.previous_delay,
+            cumulative_delay: snapshot.cumulative_delay,
+            rng: fastrand::Rng::with_seed(snapshot.rng_state),
+        }
+    }
+
+    pub(crate) fn snapshot(&self) -> BackoffSnapshot {
+        BackoffSnapshot {
+            attempts: self.attempts,
+            current_delay: self.current_delay,
+            previous_delay: self.previous_delay,
+            cumulative_delay: self.cumulative_delay,
+            rng_state: self.rng.get_seed(),
+        }
+    }
+}
+
+impl Iterator for FibonacciBackoff {
+    type Item = Duration;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.attempts >= self.config.max_retries {
+            return None;
+        }
+        self.attempts += 1;
+
+        let base = match self.current_delay {
+            None => {
+                self.current_delay = Some(self.config.initial_delay);
+                self.config.initial_delay
+            }
+            Some(current) => {
+                let mut next = current;
+
+                if current < self.config.max_delay {
+                    if let Some(previous) = self.previous_delay {
+                        next = current.saturating_add(previous);
+                        self.current_delay = Some(next);
+                    }
+                    self.previous_delay = Some(current);
+                }
+
+                next
+            }
+        };
+
+        let delay = if self.config.jitter.is_enabled() {
+            jitter(base, self.config.initial_delay, &mut self.rng)
+        } else {
+            base
+        };
+
+        self.cumulative_delay = self.cumulative_delay.saturating_add(delay);
+        Some(delay)
+    }
+}
+
+#[derive(Debug, Clone)]
+/// Backoff that draws every delay from a range around an exponentially growing interval - see
+/// [Backoff::RandomizedExponential].
+pub struct RandomizedExponentialBackoff {
+    config: RandomizedExponentialBackoffConfig,
+    attempts: usize,
+    current_delay: Option<Duration>,
+    cumulative_delay: Duration,
+    rng: fastrand::Rng,
+}
+
+impl RandomizedExponentialBackoff {
+    pub(crate) fn new(config: RandomizedExponentialBackoffConfig) -> RandomizedExponentialBackoff {
+        RandomizedExponentialBackoff {
+            config,
+            attempts: 0,
+            current_delay: None,
+            cumulative_delay: Duration::ZERO,
+            rng: rng(config.jitter.seed()),
+        }
+    }
+
+    pub(crate) fn resume(
+        config: RandomizedExponentialBackoffConfig,
+        snapshot: &BackoffSnapshot,
+    ) -> RandomizedExponentialBackoff {
+        RandomizedExponentialBackoff {
+            config,
+            attempts: snapshot.attempts,
+            current_delay: snapshot.current_delay,
+            cumulative_delay: snapshot.cumulative_delay,
+            rng: fastrand::Rng::with_seed(snapshot.rng_state),
+        }
+    }
+
+    pub(crate) fn snapshot(&self) -> BackoffSnapshot {
+        BackoffSnapshot {
+            attempts: self.attempts,
+            current_delay: self.current_delay,
+            previous_delay: None,
+            cumulative_delay: self.cumulative_delay,
+            rng_state: self.rng.get_seed(),
+        }
+    }
+
+    /// Returns the interval of the retry following the one with the interval `current`.
+    fn next_interval(&self, current: Option<Duration>) -> Duration {
+        match current {
+            None => self.config.initial_interval,
+            Some(current) => {
+                scale(current, f64::from(self.config.multiplier)).min(self.config.max_interval)
+            }
+        }
+    }
+
+    /// Returns the randomization factor, or `0.0` if jitter is disabled.
+    fn randomization_factor(&self) -> f64 {
+        if self.config.jitter.is_enabled() {
+            f64::from(self.config.randomization_factor)
+        } else {
+            0.0
+        }
+    }
+
+    /// Returns the amount of following retries that fit into the maximum elapsed time even with the largest delays.
+    fn guaranteed_retries(&self) -> usize {
+        let randomization_factor = self.randomization_factor();
+        let worst_case = |interval: Duration| {
+            if randomization_factor > 0.0 {
+                scale(interval, 1.0 + randomization_factor)
+            } else {
+                interval
+            }
+        };
+
+        let mut left = self.config.max_retries.saturating_sub(self.attempts);
+        let mut current = self.current_delay;
+        let mut cumulative = self.cumulative_delay;
+        let mut retries = 0;
+
+        while left > 0 {
+            let interval = self.next_interval(current);
+            let delay = worst_case(interval);
+
+            // Once the interval stops changing, the remaining retries can be counted at once.
+            if current == Some(interval) {
+                let fitting = self
+                    .config
+                    .max_elapsed_time
+                    .saturating_sub(cumulative)
+                    .as_nanos()
+                    .checked_div(delay.as_nanos())
+                    .map_or(left, |fitting| fitting.min(left as u128) as usize);
+                return retries + fitting;
+            }
+
+            if cumulative.saturating_add(delay) > self.config.max_elapsed_time {
+                break;
+            }
+
+            cumulative = cumulative.saturating_add(delay);
+            current = Some(interval);
+            retries += 1;
+            left -= 1;
+        }
+
+        retries
+    }
+}
+
+/// Multiplies `delay` by `factor`, saturating at [Duration::MAX].
+pub(crate) fn scale(delay: Duration, factor: f64) -> Duration {
+    Duration::try_from_secs_f64(delay.as_secs_f64() * factor).unwrap_or(Duration::MAX)
+}
+
+impl Iterator for RandomizedExponentialBackoff {
+    type Item = Duration;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.attempts >= self.config.max_retries {
+            return None;
+        }
+        self.attempts += 1;
+
+        let interval = self.next_interval(self.current_delay);
+
+        let randomization_factor = self.randomization_factor();
+        let delay = if randomization_factor > 0.0 {
+            let random = 1.0 - randomization_factor + 2.0 * randomization_factor * self.rng.f64();
+            scale(interval, random)
+        } else {
+            interval
+        };
+
+        if self.cumulative_delay.saturating_add(delay) > self.config.max_elapsed_time {
+            return None;
+        }
+
+        self.cumulative_delay = self.cumulative_delay.saturating_add(delay);
+        self.current_delay = Some(interval);
+        Some(delay)
+    }
+}
+
+#[derive(Debug, Clone)]
+/// Backoff that delays by a polynomial of the retry number - see [Backoff::Polynomial].
+pub struct PolynomialBackoff {
+    config: PolynomialBackoffConfig,
+    attempts: usize,
+    cumulative_delay: Duration,
+    rng: fastrand::Rng,
+}
+
+impl PolynomialBackoff {
+    pub(crate) fn new(config: PolynomialBackoffConfig) -> PolynomialBackoff {
+        PolynomialBackoff {
+            config,
+            attempts: 0,
+            cumulative_delay: Duration::ZERO,
+            rng: rng(config.jitter.seed()),
+        }
+    }
+
+    pub(crate) fn resume(
+        config: PolynomialBackoffConfig,
+        snapshot: &BackoffSnapshot,
+    ) -> PolynomialBackoff {
+        PolynomialBackoff {
+            config,
+            attempts: snapshot.attempts,
+            cumulative_delay: snapshot.cumulative_delay,
+            rng: fastrand::Rng::with_seed(snapshot.rng_state),
+        }
+    }
+
+    pub(crate) fn snapshot(&self) -> BackoffSnapshot {
+        BackoffSnapshot {
+            attempts: self.attempts,
+            current_delay: None,
+            previous_delay: None,
+            cumulative_delay: self.cumulative_delay,
+            rng_state: self.rng.get_seed(),
+        }
+    }
+}
+
+/// Returns the delay of the `n`-th retry (starting at `0`) of a [Backoff::Polynomial] before padding.
+pub(crate) fn polynomial_delay(config: &PolynomialBackoffConfig, n: usize) -> Duration {
+    let raised = (n as f64).powf(f64::from(config.exponent));
+    config
+        .base
+        .saturating_add(scale(config.scale, raised))
+        .min(config.max_delay)
+}
+
+/// Returns the largest padding of the `n`-th retry (starting at `0`) of a [Backoff::Polynomial].
+pub(crate) fn polynomial_padding(config: &PolynomialBackoffConfig, n: usize) -> Duration {
+    u32::try_from(n + 1)
+        .ok()
+        .and_then(|retries| config.padding.checked_mul(retries))
+        .unwrap_or(Duration::MAX)
+}
+
+impl Iterator for PolynomialBackoff {
+    type Item = Duration;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.attempts >= self.config.max_retries {
+            return None;
+        }
+        let n = self.attempts;
+        self.attempts += 1;
+
+        let base = polynomial_delay(&self.config, n);
+        let delay = if self.config.jitter.is_enabled() {
+            jitter(base, polynomial_padding(&self.config, n), &mut self.rng)
+                .min(self.config.max_delay)
+        } else {
+            base
+        };
+
+        self.cumulative_delay = self.cumulative_delay.saturating_add(delay);
+        Some(delay)
+    }
+}
+
+#[derive(Debug, Clone)]
+/// Backoff that draws every delay uniformly from a range - see [Backoff::Random].
+pub struct RandomBackoff {
+    config: RandomBackoffConfig,
+    attempts: usize,
+    cumulative_delay: Duration,
+    rng: fastrand::Rng,
+}
+
+impl RandomBackoff {
+    pub(crate) fn new(config: RandomBackoffConfig) -> RandomBackoff {
+        RandomBackoff {
+            config,
+            attempts: 0,
+            cumulative_delay: Duration::ZERO,
+            rng: rng(config.seed),
+        }
+    }
+
+    pub(crate) fn resume(config: RandomBackoffConfig, snapshot: &BackoffSnapshot) -> RandomBackoff {
+        RandomBackoff {
+            config,
+            attempts: snapshot.attempts,
+            cumulative_delay: snapshot.cumulative_delay,
+            rng: fastrand::Rng::with_seed(snapshot.rng_state),
+        }
+    }
+
+    pub(crate) fn snapshot(&self) -> BackoffSnapshot {
+        BackoffSnapshot {
+            attempts: self.attempts,
+            current_delay: None,
+            previous_delay: None,
+            cumulative_delay: self.cumulative_delay,
+            rng_state: self.rng.get_seed(),
+        }
+    }
+}
+
+impl Iterator for RandomBackoff {
+    type Item = Duration;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.attempts >= self.config.max_retries {
+            return None;
+        }
+        self.attempts += 1;
+
+        let range = self.config.max_delay.saturating_sub(self.config.min_delay);
+        let delay = jitter(self.config.min_delay, range, &mut self.rng);
+
+        self.cumulative_delay = self.cumulative_delay.saturating_add(delay);
+        Some(delay)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use backon::BackoffBuilder;
+
+    /// Builds the equivalent [backon] backoff of `config`.
+    fn backon(config: BackoffConfig) -> Box<dyn Iterator<Item = Duration>> {
+        match config {
+            BackoffConfig::Constant(c) => {
+                let mut builder = backon::ConstantBuilder::new()
+                    .with_delay(c.delay)
+                    .with_max_times(c.max_retries);
+                if let Some(seed) = c.jitter.seed() {
+                    builder = builder.with_jitter().with_jitter_seed(seed);
+                }
+                Box::new(builder.build())
+            }
+            BackoffConfig::Exponential(c) => {
+                let mut builder = backon::ExponentialBuilder::new()
+                    .with_min_delay(c.initial_delay)
+                    .with_factor(c.factor)
+                    .with_max_delay(c.max_delay)
+                    .with_max_times(c.max_retries)
+                    .with_total_delay(Some(c.max_total_delay));
+                if let Some(seed) = c.jitter.seed() {
+                    builder = builder.with_jitter().with_jitter_seed(seed);
+                }
+                Box::new(builder.build())
+            }
+            BackoffConfig::Fibonacci(c) => {
+                let mut builder = backon::FibonacciBuilder::new()
+                    .with_min_delay(c.initial_delay)
+                    .with_max_delay(c.max_delay)
+                    .with_max_times(c.max_retries);
+                if let Some(seed) = c.jitter.seed() {
+                    builder = builder.with_jitter().with_jitter_seed(seed);
+                }
+                Box::new(builder.build())
+            }
+            _ => unreachable!(),
+        }
+    }
+
+    #[test]
+    fn yields_same_delays_as_backon() {
+        let configs = [
+            BackoffConfig::Constant(ConstantBackoffConfig {
+                delay: Duration::from_millis(300),
+                max_retries: 20,
+                ..Default::default()
+            }),
+            BackoffConfig::Exponential(ExponentialBackoffConfig {
+                initial_delay: Duration::from_millis(70),
+                factor: 1.7,
+                max_delay: Duration::from_secs(3),
+                max_retries: 20,
+                max_total_delay: Duration::from_secs(25),
+                ..Default::default()
+            }),
+            BackoffConfig::Exponential(ExponentialBackoffConfig {
+                initial_delay: Duration::from_millis(70),
+                max_retries: 20,
+                jitter: Jitter::Disabled,
+                ..Default::default()
+            }),
+            BackoffConfig::Fibonacci(FibonacciBackoffConfig {
+                initial_delay: Duration::from_millis(70),
+                max_delay: Duration::from_secs(2),
+                max_retries: 20,
+                ..Default::default()
+            }),
+            BackoffConfig::Fibonacci(FibonacciBackoffConfig {
+                initial_delay: Duration::from_millis(70),
+                max_retries: 20,
+                jitter: Jitter::Disabled,
+                ..Default::default()
+            }),
+        ];
+
+        for config in configs {
+            for seed in 0..100 {
+                let config = config.with_jitter_seed(seed);
+
+                assert_eq!(
+                    config.build().collect::<Vec<_>>(),
+                    backon(config).collect::<Vec<_>>(),
+                    "{config:?}"
+                );
+            }
+        }
+    }
+
+    fn seeded() -> BackoffConfig {
+        BackoffConfig::Exponential(ExponentialBackoffConfig {
+            initial_delay: Duration::from_secs(1),
+            factor: 2_f32,
+            max_delay: Duration::from_secs(8),
+            max_retries: 6,
+            max_total_delay: Duration::from_secs(60),
+            jitter: Jitter::Seeded(42),
+        })
+    }
+
+    #[test]
+    fn clone_continues_with_same_delays() {
+        let mut backoff = seeded().build();
+        backoff.next();
+
+        let clone = backoff.clone();
+
+        assert_eq!(clone.collect::<Vec<_>>(), backoff.collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn reset_restarts_from_first_retry() {
+        let expected = seeded().build().collect::<Vec<_>>();
+        let mut backoff = seeded().build();
+        backoff.by_ref().take(3).for_each(drop);
+
+        backoff.reset();
+
+        assert_eq!(backoff.attempt(), 0);
+        assert_eq!(backoff.cumulative_delay(), Duration::ZERO);
+        assert_eq!(backoff.collect::<Vec<_>>(), expected);
+    }
+
+    #[test]
+    fn reset_keeps_adaptive_state() {
+        let config = BackoffConfig::Adaptive(AdaptiveBackoffConfig {
+            initial_delay: Duration::from_secs(1),
+            min_delay: Duration::from_secs(1),
+            max_delay: Duration::from_secs(8),
+            increase_factor: 2_f32,
+            max_retries: 2,
+            jitter: Jitter::Disabled,
+            ..Default::default()
+        });
+        let mut backoff = config.build();
+        backoff.by_ref().for_each(drop);
+        let Backoff::Adaptive(adaptive) = &backoff else {
+            unreachable!()
+        };
+        adaptive.state().record_failure();
+        adaptive.state().record_failure();
+
+        backoff.reset();
+
+        assert_eq!(
+            backoff.collect::<Vec<_>>(),
+            vec![Duration::from_secs(4), Duration::from_secs(8)]
+        );
+    }
+
+    #[test]
+    fn exposes_progress() {
+        let mut backoff = BackoffConfig::Constant(ConstantBackoffConfig {
+            delay: Duration::from_secs(1),
+            max_retries: 3,
+            jitter: Jitter::Disabled,
+        })
+        .build();
+
+        assert_eq!(backoff.size_hint(), (3, Some(3)));
+        backoff.next();
+        backoff.next();
+
+        assert_eq!(backoff.attempt(), 2);
+        assert_eq!(backoff.remaining_retries(), 1);
+        assert_eq!(backoff.cumulative_delay(), Duration::from_secs(2));
+        assert_eq!(backoff.size_hint(), (1, Some(1)));
+        assert_eq!(backoff.config(), backoff.clone().config());
+
+        backoff.next();
+        assert_eq!(backoff.remaining_retries(), 0);
+        assert_eq!(backoff.size_hint(), (0, Some(0)));
+        assert_eq!(backoff.next(), None);
+        assert_eq!(backoff.remaining_retries(), 0);
+    }
+
+    #[test]
+    fn returns_config() {
+        for config in [
+            seeded(),
+            BackoffConfig::Constant(Default::default()),
+            BackoffConfig::Fibonacci(Default::default()),
+            BackoffConfig::Adaptive(Default::default()),
+            BackoffConfig::RandomizedExponential(Default::default()),
+            BackoffConfig::Polynomial(Default::default()),
+            BackoffConfig::Random(Default::default()),
+            BackoffConfig::NoBackoff,
+        ] {
+            assert_eq!(config.build().config(), config);
+        }
+    }
+
+    #[test]
+    fn size_hint_bounds_exponential_backoff() {
+        let configs = [
+            seeded(),
+            BackoffConfig::Exponential(ExponentialBackoffConfig {
+                initial_delay: Duration::from_secs(1),
+                max_delay: Duration::from_secs(4),
+                max_retries: 100,
+                max_total_delay: Duration::from_secs(60),
+                ..Default::default()
+            }),
+            BackoffConfig::Exponential(ExponentialBackoffConfig {
+                max_retries: usize::MAX,
+                jitter: Jitter::Disabled,
+                ..Default::default()
+            }),
+            BackoffConfig::RandomizedExponential(RandomizedExponentialBackoffConfig {
+                max_elapsed_time: Duration::from_secs(60),
+                ..Default::default()
+            }),
+        ];
+
+        for config in configs {
+            for seed in 0..100 {
+                let mut backoff = config.with_jitter_seed(seed).build();
+
+                loop {
+                    let (lower, upper) = backoff.size_hint();
+                    let count = backoff.clone().take(1000).count();
+
+                    assert!(lower <= count, "{config:?}");
+                    assert!(upper.is_none_or(|upper| count <= upper), "{config:?}");
+
+                    if backoff.next().is_none() {
+                        break;
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn size_hint_is_exact_without_jitter() {
+        let backoff = BackoffConfig::Exponential(ExponentialBackoffConfig {
+            initial_delay: Duration::from_secs(1),
+            factor: 2_f32,
+            max_delay: Duration::from_secs(4),
+            max_retries: 10,
+            max_total_delay: Duration::from_secs(15),
+            jitter: Jitter::Disabled,
+        })
+        .build();
+
+        // 1s + 2s + 4s + 4s + 4s
+        assert_eq!(backoff.size_hint(), (5, Some(10)));
+        assert_eq!(backoff.count(), 5);
+    }
+
+    fn randomized_exponential(jitter: Jitter) -> BackoffConfig {
+        BackoffConfig::RandomizedExponential(RandomizedExponentialBackoffConfig {
+            initial_interval: Duration::from_millis(100),
+            randomization_factor: 0.5,
+            multiplier: 2_f32,
+            max_interval: Duration::from_millis(400),
+            max_elapsed_time: Duration::from_secs(2),
+            max_retries: usize::MAX,
+            jitter,
+        })
+    }
+
+    #[test]
+    fn randomized_exponential_without_jitter() {
+        let delays = randomized_exponential(Jitter::Disabled)
+            .build()
+            .map(|delay| delay.as_millis())
+            .collect::<Vec<_>>();
+
+        // Stops before exceeding the max elapsed time of 2s.
+        assert_eq!(delays, vec![100, 200, 400, 400, 400, 400]);
+    }
+
+    #[test]
+    fn randomized_exponential_with_seeded_jitter() {
+        let delays = randomized_exponential(Jitter::Seeded(42))
+            .build()
+            .map(|delay| delay.as_millis())
+            .collect::<Vec<_>>();
+
+        assert_eq!(delays, vec![129, 198, 521, 215, 416, 382]);
+
+        for seed in 0..100 {
+            let mut backoff = randomized_exponential(Jitter::Seeded(seed)).build();
+            let mut interval = Duration::from_millis(100);
+
+            for delay in backoff.by_ref() {
+                assert!(
+                    interval / 2 <= delay && delay <= interval * 3 / 2,
+                    "{delay:?}"
+                );
+                interval = (interval * 2).min(Duration::from_millis(400));
+            }
+            assert!(backoff.cumulative_delay() <= Duration::from_secs(2));
+        }
+    }
+
+    #[test]
+    fn polynomial_without_jitter() {
+        let delays = BackoffConfig::Polynomial(PolynomialBackoffConfig {
+            max_retries: 6,
+            jitter: Jitter::Disabled,
+            ..Default::default()
+        })
+        .build()
+        .map(|delay| delay.as_secs())
+        .collect::<Vec<_>>();
+
+        // 15s + n^4 * 1s up to 30s, without padding
+        assert_eq!(delays, vec![15, 16, 30, 30, 30, 30]);
+    }
+
+    #[test]
+    fn polynomial_with_max_delay_and_seeded_jitter() {
+        let config = BackoffConfig::Polynomial(PolynomialBackoffConfig {
+            base: Duration::from_secs(1),
+            exponent: 2_f32,
+            scale: Duration::from_secs(2),
+            padding: Duration::from_secs(1),
+            max_delay: Duration::from_secs(30),
+            max_retries: 6,
+            jitter: Jitter::Seeded(42),
+        });
+
+        let delays = config
+            .build()
+            .map(|delay| delay.as_millis())
+            .collect::<Vec<_>>();
+
+        // 1s + n^2 * 2s, plus up to (n + 1) * 1s, up to 30s
+        assert_eq!(delays, vec![1463, 3640, 11188, 20799, 30000, 30000]);
+    }
+
+    #[test]
+    fn random_with_seeded_jitter() {
+        let config = BackoffConfig::Random(RandomBackoffConfig {
+            min_delay: Duration::from_secs(1),
+            max_delay: Duration::from_secs(5),
+            max_retries: 6,
+            seed: Some(42),
+        });
+
+        let delays = config
+            .build()
+            .map(|delay| delay.as_millis())
+            .collect::<Vec<_>>();
+
+        assert_eq!(delays, vec![2853, 2281, 3917, 2799, 2459, 2286]);
+
+        for seed in 0..100 {
+            for delay in config.with_jitter_seed(seed).build() {
+                assert!((Duration::from_secs(1)..Duration::from_secs(5)).contains(&delay));
+            }
+        }
+    }
 }
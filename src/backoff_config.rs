@@ -1,11 +1,31 @@
 use crate::*;
-use duration_str::*;
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter};
 use std::time::Duration;
 
-#[derive(Debug, Clone, Copy, Deserialize, PartialEq)]
+#[derive(Debug, Clone, Copy, Serialize, PartialEq)]
 #[serde(tag = "strategy")]
 /// Configuration for [Backoff].
+///
+/// Deserialized from a map whose `strategy` key selects the variant, e.g. `strategy = "Exponential"`. Strategy names
+/// are case-insensitive and have aliases: `exp`, `fib`, `fixed`, and `none` / `off` / `disabled` for
+/// [BackoffConfig::NoBackoff]. Serialization always uses the canonical names.
+///
+/// Simple configs can also be given as scalars instead of maps: a strategy name (e.g. `"none"`) for the strategy with
+/// default values, a delay with a unit (e.g. `"500ms"`) for [BackoffConfig::Constant] with that delay, or an amount of
+/// retries (e.g. `3`) for [BackoffConfig::Constant] with that amount of retries. Bare numbers are always amounts of
+/// retries in this form, even as strings: `"500"` is 500 retries, not a delay.
+///
+/// Keys that are not valid for the `strategy` are ignored - see [BackoffConfig::deserialize_strict] and
+/// [CheckedBackoffConfig] to detect them.
+///
+/// Durations are given as strings with a unit (e.g. `"500ms"`), as ISO 8601 durations or as bare numbers (e.g. `500` or
+/// `"500"`) of milliseconds. An optional `duration_unit = "seconds"` key switches the bare numbers of the config to
+/// seconds - see [deserialize_duration].
+///
+/// An optional `version` key selects the schema version - see [CURRENT_SCHEMA_VERSION]. Configs of older versions, e.g.
+/// with `jitter_enabled` and `jitter_seed` instead of `jitter`, are migrated and reported in
+/// [CheckedBackoffConfig::deprecations].
 pub enum BackoffConfig {
     /// Configuration for [Backoff::Constant].
     Constant(ConstantBackoffConfig),
@@ -16,6 +36,18 @@ pub enum BackoffConfig {
     /// Configuration for [Backoff::Fibonacci].
     Fibonacci(FibonacciBackoffConfig),
 
+    /// Configuration for [Backoff::Adaptive].
+    Adaptive(AdaptiveBackoffConfig),
+
+    /// Configuration for [Backoff::RandomizedExponential].
+    RandomizedExponential(RandomizedExponentialBackoffConfig),
+
+    /// Configuration for [Backoff::Polynomial].
+    Polynomial(PolynomialBackoffConfig),
+
+    /// Configuration for [Backoff::Random].
+    Random(RandomBackoffConfig),
+
     /// Configuration for [Backoff::NoBackoff].
     NoBackoff,
 }
@@ -38,13 +70,44 @@ impl From<FibonacciBackoffConfig> for BackoffConfig {
     }
 }
 
-#[derive(Debug, smart_default::SmartDefault, Clone, Copy, Deserialize, PartialEq)]
+impl From<AdaptiveBackoffConfig> for BackoffConfig {
+    fn from(config: AdaptiveBackoffConfig) -> BackoffConfig {
+        BackoffConfig::Adaptive(config)
+    }
+}
+
+impl From<RandomizedExponentialBackoffConfig> for BackoffConfig {
+    fn from(config: RandomizedExponentialBackoffConfig) -> BackoffConfig {
+        BackoffConfig::RandomizedExponential(config)
+    }
+}
+
+impl From<PolynomialBackoffConfig> for BackoffConfig {
+    fn from(config: PolynomialBackoffConfig) -> BackoffConfig {
+        BackoffConfig::Polynomial(config)
+    }
+}
+
+impl From<RandomBackoffConfig> for BackoffConfig {
+    fn from(config: RandomBackoffConfig) -> BackoffConfig {
+        BackoffConfig::Random(config)
+    }
+}
+
+#[derive(Debug, smart_default::SmartDefault, Clone, Copy, Deserialize, Serialize, PartialEq)]
 /// Configuration for [Backoff::Constant].
+///
+/// Built in code with [ConstantBackoffConfig::new] and its `const` setters.
+#[non_exhaustive]
 pub struct ConstantBackoffConfig {
     /// Backoff delay.
     ///
     /// Defaults to `500 millis` - see [defaults::delay].
-    #[serde(default = "defaults::delay", deserialize_with = "deserialize_duration")]
+    #[serde(
+        default = "defaults::delay",
+        deserialize_with = "deserialize_duration",
+        serialize_with = "serialize_duration"
+    )]
     #[default(defaults::delay())]
     pub delay: Duration,
 
@@ -55,28 +118,28 @@ pub struct ConstantBackoffConfig {
     #[default(defaults::max_retries())]
     pub max_retries: usize,
 
-    /// Whether jitter is enabled.
-    ///
-    /// Defaults to `true` - see [defaults::jitter_enabled].
-    #[serde(default = "defaults::jitter_enabled")]
-    #[default(defaults::jitter_enabled())]
-    pub jitter_enabled: bool,
-
-    /// Random seed to initialize the random jitter generator.
+    /// Jitter added to the delays.
     ///
-    /// Defaults to `None` - see [defaults::jitter_seed].
-    #[serde(default = "defaults::jitter_seed")]
-    #[default(defaults::jitter_seed())]
-    pub jitter_seed: Option<u64>,
+    /// Defaults to [Jitter::Random] - see [defaults::jitter].
+    #[serde(default = "defaults::jitter")]
+    #[default(defaults::jitter())]
+    pub jitter: Jitter,
 }
 
-#[derive(Debug, smart_default::SmartDefault, Clone, Copy, Deserialize, PartialEq)]
+#[derive(Debug, smart_default::SmartDefault, Clone, Copy, Deserialize, Serialize, PartialEq)]
 /// Configuration for [Backoff::Exponential].
+///
+/// Built in code with [ExponentialBackoffConfig::new] and its `const` setters.
+#[non_exhaustive]
 pub struct ExponentialBackoffConfig {
     /// Initial backoff delay.
     ///
     /// Defaults to `500 millis` - see [defaults::delay].
-    #[serde(default = "defaults::delay", deserialize_with = "deserialize_duration")]
+    #[serde(
+        default = "defaults::delay",
+        deserialize_with = "deserialize_duration",
+        serialize_with = "serialize_duration"
+    )]
     #[default(defaults::delay())]
     pub initial_delay: Duration,
 
@@ -92,7 +155,8 @@ pub struct ExponentialBackoffConfig {
     /// Defaults to `30 seconds` - see [defaults::max_delay].
     #[serde(
         default = "defaults::max_delay",
-        deserialize_with = "deserialize_duration"
+        deserialize_with = "deserialize_duration",
+        serialize_with = "serialize_duration"
     )]
     #[default(defaults::max_delay())]
     pub max_delay: Duration,
@@ -109,66 +173,941 @@ pub struct ExponentialBackoffConfig {
     /// Defaults to `60 seconds` - see [defaults::max_total_delay]
     #[serde(
         default = "defaults::max_total_delay",
-        deserialize_with = "deserialize_duration"
+        deserialize_with = "deserialize_duration",
+        serialize_with = "serialize_duration"
     )]
     #[default(defaults::max_total_delay())]
     pub max_total_delay: Duration,
 
-    /// Whether jitter is enabled.
-    ///
-    /// Defaults to `true` - see [defaults::jitter_enabled].
-    #[serde(default = "defaults::jitter_enabled")]
-    #[default(defaults::jitter_enabled())]
-    pub jitter_enabled: bool,
+    /// Jitter added to the delays.
+    ///
+    /// Defaults to [Jitter::Random] - see [defaults::jitter].
+    #[serde(default = "defaults::jitter")]
+    #[default(defaults::jitter())]
+    pub jitter: Jitter,
+}
+
+#[derive(Debug, smart_default::SmartDefault, Clone, Copy, Deserialize, Serialize, PartialEq)]
+/// Configuration for [Backoff::Fibonacci].
+///
+/// Built in code with [FibonacciBackoffConfig::new] and its `const` setters.
+#[non_exhaustive]
+pub struct FibonacciBackoffConfig {
+    /// Initial backoff delay.
+    ///
+    /// Defaults to `500 millis` - see [defaults::delay].
+    #[serde(
+        default = "defaults::delay",
+        deserialize_with = "deserialize_duration",
+        serialize_with = "serialize_duration"
+    )]
+    #[default(defaults::delay())]
+    pub initial_delay: Duration,
+
+    /// Maximum backoff delay.
+    ///
+    /// Defaults to `30 seconds` - see [defaults::max_delay].
+    #[serde(
+        default = "defaults::max_delay",
+        deserialize_with = "deserialize_duration",
+        serialize_with = "serialize_duration"
+    )]
+    #[default(defaults::max_delay())]
+    pub max_delay: Duration,
+
+    /// Maximum amount of retries.
+    ///
+    /// Defaults to `4` - see [defaults::max_retries].
+    #[serde(default = "defaults::max_retries")]
+    #[default(defaults::max_retries())]
+    pub max_retries: usize,
+
+    /// Jitter added to the delays.
+    ///
+    /// Defaults to [Jitter::Random] - see [defaults::jitter].
+    #[serde(default = "defaults::jitter")]
+    #[default(defaults::jitter())]
+    pub jitter: Jitter,
+}
+
+impl ConstantBackoffConfig {
+    /// Creates a new [ConstantBackoffConfig] with default values, usable in `const` and `static` items.
+    pub const fn new() -> ConstantBackoffConfig {
+        ConstantBackoffConfig {
+            delay: defaults::delay(),
+            max_retries: defaults::max_retries(),
+            jitter: defaults::jitter(),
+        }
+    }
+
+    /// Sets [ConstantBackoffConfig::delay].
+    pub const fn delay(mut self, delay: Duration) -> ConstantBackoffConfig {
+        self.delay = delay;
+        self
+    }
+
+    /// Sets [ConstantBackoffConfig::max_retries].
+    pub const fn max_retries(mut self, max_retries: usize) -> ConstantBackoffConfig {
+        self.max_retries = max_retries;
+        self
+    }
+
+    /// Sets [ConstantBackoffConfig::jitter].
+    pub const fn jitter(mut self, jitter: Jitter) -> ConstantBackoffConfig {
+        self.jitter = jitter;
+        self
+    }
+}
+
+impl ExponentialBackoffConfig {
+    /// Creates a new [ExponentialBackoffConfig] with default values, usable in `const` and `static` items:
+    ///
+    /// ```
+    /// use backoff_config::*;
+    /// use std::time::Duration;
+    ///
+    /// static BACKOFF: ExponentialBackoffConfig = ExponentialBackoffConfig::new()
+    ///     .initial_delay(Duration::from_millis(100))
+    ///     .factor(3.0)
+    ///     .max_retries(5);
+    ///
+    /// assert_eq!(BACKOFF.max_delay, defaults::max_delay());
+    /// ```
+    pub const fn new() -> ExponentialBackoffConfig {
+        ExponentialBackoffConfig {
+            initial_delay: defaults::delay(),
+            factor: defaults::factor(),
+            max_delay: defaults::max_delay(),
+            max_retries: defaults::max_retries(),
+            max_total_delay: defaults::max_total_delay(),
+            jitter: defaults::jitter(),
+        }
+    }
+
+    /// Sets [ExponentialBackoffConfig::initial_delay].
+    pub const fn initial_delay(mut self, initial_delay: Duration) -> ExponentialBackoffConfig {
+        self.initial_delay = initial_delay;
+        self
+    }
+
+    /// Sets [ExponentialBackoffConfig::factor].
+    pub const fn factor(mut self, factor: f32) -> ExponentialBackoffConfig {
+        self.factor = factor;
+        self
+    }
+
+    /// Sets [ExponentialBackoffConfig::max_delay].
+    pub const fn max_delay(mut self, max_delay: Duration) -> ExponentialBackoffConfig {
+        self.max_delay = max_delay;
+        self
+    }
+
+    /// Sets [ExponentialBackoffConfig::max_retries].
+    pub const fn max_retries(mut self, max_retries: usize) -> ExponentialBackoffConfig {
+        self.max_retries = max_retries;
+        self
+    }
+
+    /// Sets [ExponentialBackoffConfig::max_total_delay].
+    pub const fn max_total_delay(mut self, max_total_delay: Duration) -> ExponentialBackoffConfig {
+        self.max_total_delay = max_total_delay;
+        self
+    }
+
+    /// Sets [ExponentialBackoffConfig::jitter].
+    pub const fn jitter(mut self, jitter: Jitter) -> ExponentialBackoffConfig {
+        self.jitter = jitter;
+        self
+    }
+}
+
+impl FibonacciBackoffConfig {
+    /// Creates a new [FibonacciBackoffConfig] with default values, usable in `const` and `static` items.
+    pub const fn new() -> FibonacciBackoffConfig {
+        FibonacciBackoffConfig {
+            initial_delay: defaults::delay(),
+            max_delay: defaults::max_delay(),
+            max_retries: defaults::max_retries(),
+            jitter: defaults::jitter(),
+        }
+    }
+
+    /// Sets [FibonacciBackoffConfig::initial_delay].
+    pub const fn initial_delay(mut self, initial_delay: Duration) -> FibonacciBackoffConfig {
+        self.initial_delay = initial_delay;
+        self
+    }
+
+    /// Sets [FibonacciBackoffConfig::max_delay].
+    pub const fn max_delay(mut self, max_delay: Duration) -> FibonacciBackoffConfig {
+        self.max_delay = max_delay;
+        self
+    }
+
+    /// Sets [FibonacciBackoffConfig::max_retries].
+    pub const fn max_retries(mut self, max_retries: usize) -> FibonacciBackoffConfig {
+        self.max_retries = max_retries;
+        self
+    }
+
+    /// Sets [FibonacciBackoffConfig::jitter].
+    pub const fn jitter(mut self, jitter: Jitter) -> FibonacciBackoffConfig {
+        self.jitter = jitter;
+        self
+    }
+}
+
+#[derive(Debug, smart_default::SmartDefault, Clone, Copy, Deserialize, Serialize, PartialEq)]
+/// Configuration for [Backoff::Adaptive].
+///
+/// The base delay is kept in an [AdaptiveState]: it grows multiplicatively by [AdaptiveBackoffConfig::increase_factor]
+/// on every failure and shrinks additively by [AdaptiveBackoffConfig::decrease_step] on every success, staying within
+/// [AdaptiveBackoffConfig::min_delay] and [AdaptiveBackoffConfig::max_delay].
+///
+/// Built in code with [AdaptiveBackoffConfig::new] and its `const` setters.
+#[non_exhaustive]
+pub struct AdaptiveBackoffConfig {
+    /// Initial base backoff delay.
+    ///
+    /// Defaults to `500 millis` - see [defaults::delay].
+    #[serde(
+        default = "defaults::delay",
+        deserialize_with = "deserialize_duration",
+        serialize_with = "serialize_duration"
+    )]
+    #[default(defaults::delay())]
+    pub initial_delay: Duration,
+
+    /// Minimum base backoff delay.
+    ///
+    /// Defaults to `100 millis` - see [defaults::min_delay].
+    #[serde(
+        default = "defaults::min_delay",
+        deserialize_with = "deserialize_duration",
+        serialize_with = "serialize_duration"
+    )]
+    #[default(defaults::min_delay())]
+    pub min_delay: Duration,
+
+    /// Maximum base backoff delay.
+    ///
+    /// Defaults to `30 seconds` - see [defaults::max_delay].
+    #[serde(
+        default = "defaults::max_delay",
+        deserialize_with = "deserialize_duration",
+        serialize_with = "serialize_duration"
+    )]
+    #[default(defaults::max_delay())]
+    pub max_delay: Duration,
+
+    /// Factor the base delay is multiplied by on failure.
+    ///
+    /// Defaults to `2.0` - see [defaults::factor].
+    #[serde(default = "defaults::factor")]
+    #[default(defaults::factor())]
+    pub increase_factor: f32,
+
+    /// Step the base delay is decreased by on success.
+    ///
+    /// Defaults to `100 millis` - see [defaults::decrease_step].
+    #[serde(
+        default = "defaults::decrease_step",
+        deserialize_with = "deserialize_duration",
+        serialize_with = "serialize_duration"
+    )]
+    #[default(defaults::decrease_step())]
+    pub decrease_step: Duration,
+
+    /// Maximum amount of retries.
+    ///
+    /// Defaults to `4` - see [defaults::max_retries].
+    #[serde(default = "defaults::max_retries")]
+    #[default(defaults::max_retries())]
+    pub max_retries: usize,
+
+    /// Jitter added to the delays.
+    ///
+    /// Defaults to [Jitter::Random] - see [defaults::jitter].
+    #[serde(default = "defaults::jitter")]
+    #[default(defaults::jitter())]
+    pub jitter: Jitter,
+}
+
+impl AdaptiveBackoffConfig {
+    /// Creates a new [AdaptiveBackoffConfig] with default values, usable in `const` and `static` items.
+    pub const fn new() -> AdaptiveBackoffConfig {
+        AdaptiveBackoffConfig {
+            initial_delay: defaults::delay(),
+            min_delay: defaults::min_delay(),
+            max_delay: defaults::max_delay(),
+            increase_factor: defaults::factor(),
+            decrease_step: defaults::decrease_step(),
+            max_retries: defaults::max_retries(),
+            jitter: defaults::jitter(),
+        }
+    }
+
+    /// Sets [AdaptiveBackoffConfig::initial_delay].
+    pub const fn initial_delay(mut self, initial_delay: Duration) -> AdaptiveBackoffConfig {
+        self.initial_delay = initial_delay;
+        self
+    }
+
+    /// Sets [AdaptiveBackoffConfig::min_delay].
+    pub const fn min_delay(mut self, min_delay: Duration) -> AdaptiveBackoffConfig {
+        self.min_delay = min_delay;
+        self
+    }
+
+    /// Sets [AdaptiveBackoffConfig::max_delay].
+    pub const fn max_delay(mut self, max_delay: Duration) -> AdaptiveBackoffConfig {
+        self.max_delay = max_delay;
+        self
+    }
+
+    /// Sets [AdaptiveBackoffConfig::increase_factor].
+    pub const fn increase_factor(mut self, increase_factor: f32) -> AdaptiveBackoffConfig {
+        self.increase_factor = increase_factor;
+        self
+    }
+
+    /// Sets [AdaptiveBackoffConfig::decrease_step].
+    pub const fn decrease_step(mut self, decrease_step: Duration) -> AdaptiveBackoffConfig {
+        self.decrease_step = decrease_step;
+        self
+    }
+
+    /// Sets [AdaptiveBackoffConfig::max_retries].
+    pub const fn max_retries(mut self, max_retries: usize) -> AdaptiveBackoffConfig {
+        self.max_retries = max_retries;
+        self
+    }
+
+    /// Sets [AdaptiveBackoffConfig::jitter].
+    pub const fn jitter(mut self, jitter: Jitter) -> AdaptiveBackoffConfig {
+        self.jitter = jitter;
+        self
+    }
+}
+
+#[derive(Debug, smart_default::SmartDefault, Clone, Copy, Deserialize, Serialize, PartialEq)]
+/// Configuration for [Backoff::RandomizedExponential], the model of Google's HTTP client libraries and the `backoff`
+/// crate, with the same field names.
+///
+/// Every delay is drawn uniformly from `[interval * (1 - randomization_factor), interval * (1 + randomization_factor)]`,
+/// after which the interval is multiplied by [RandomizedExponentialBackoffConfig::multiplier] up to
+/// [RandomizedExponentialBackoffConfig::max_interval]. Retries stop once the sum of the delays would exceed
+/// [RandomizedExponentialBackoffConfig::max_elapsed_time].
+///
+/// **Note:** unlike in the ported model, [RandomizedExponentialBackoffConfig::max_elapsed_time] limits the sum of the
+/// delays, not the wall-clock time since the first attempt - see its docs.
+///
+/// Built in code with [RandomizedExponentialBackoffConfig::new] and its `const` setters.
+#[non_exhaustive]
+pub struct RandomizedExponentialBackoffConfig {
+    /// Initial interval.
+    ///
+    /// Defaults to `500 millis` - see [defaults::delay].
+    #[serde(
+        default = "defaults::delay",
+        deserialize_with = "deserialize_duration",
+        serialize_with = "serialize_duration"
+    )]
+    #[default(defaults::delay())]
+    pub initial_interval: Duration,
+
+    /// Relative range around the interval that delays are drawn from, between `0.0` and `1.0`.
+    ///
+    /// Defaults to `0.5` - see [defaults::randomization_factor].
+    #[serde(default = "defaults::randomization_factor")]
+    #[default(defaults::randomization_factor())]
+    pub randomization_factor: f32,
+
+    /// Factor the interval is multiplied by on every retry.
+    ///
+    /// Defaults to `1.5` - see [defaults::multiplier].
+    #[serde(default = "defaults::multiplier")]
+    #[default(defaults::multiplier())]
+    pub multiplier: f32,
+
+    /// Maximum interval, before randomization.
+    ///
+    /// Defaults to `60 seconds` - see [defaults::max_interval].
+    #[serde(
+        default = "defaults::max_interval",
+        deserialize_with = "deserialize_duration",
+        serialize_with = "serialize_duration"
+    )]
+    #[default(defaults::max_interval())]
+    pub max_interval: Duration,
+
+    /// Maximum sum of all delays.
+    ///
+    /// **This is not wall-clock time.** In the `backoff` crate and Google's client libraries, `max_elapsed_time` is the
+    /// time since the first attempt, including the time spent in the attempts themselves. Here it only sums the delays,
+    /// which keeps the schedule deterministic (see [BackoffConfig::simulate] and [BackoffConfig::schedule_bounds]), so
+    /// retrying slow operations takes longer than this in total. Wrap the retries in a timeout to bound the wall-clock
+    /// time.
+    ///
+    /// Defaults to `15 minutes` - see [defaults::max_elapsed_time].
+    #[serde(
+        default = "defaults::max_elapsed_time",
+        deserialize_with = "deserialize_duration",
+        serialize_with = "serialize_duration"
+    )]
+    #[default(defaults::max_elapsed_time())]
+    pub max_elapsed_time: Duration,
+
+    /// Maximum amount of retries.
+    ///
+    /// Defaults to unlimited, so only [RandomizedExponentialBackoffConfig::max_elapsed_time] stops retries as in the
+    /// ported model - see [defaults::unlimited_retries].
+    #[serde(default = "defaults::unlimited_retries")]
+    #[default(defaults::unlimited_retries())]
+    pub max_retries: usize,
+
+    /// Randomization of the delays: [Jitter::Disabled] ignores
+    /// [RandomizedExponentialBackoffConfig::randomization_factor], and [Jitter::Seeded] makes the delays reproducible.
+    ///
+    /// Defaults to [Jitter::Random] - see [defaults::jitter].
+    #[serde(default = "defaults::jitter")]
+    #[default(defaults::jitter())]
+    pub jitter: Jitter,
+}
+
+impl RandomizedExponentialBackoffConfig {
+    /// Creates a new [RandomizedExponentialBackoffConfig] with default values, usable in `const` and `static` items.
+    pub const fn new() -> RandomizedExponentialBackoffConfig {
+        RandomizedExponentialBackoffConfig {
+            initial_interval: defaults::delay(),
+            randomization_factor: defaults::randomization_factor(),
+            multiplier: defaults::multiplier(),
+            max_interval: defaults::max_interval(),
+            max_elapsed_time: defaults::max_elapsed_time(),
+            max_retries: defaults::unlimited_retries(),
+            jitter: defaults::jitter(),
+        }
+    }
+
+    /// Sets [RandomizedExponentialBackoffConfig::initial_interval].
+    pub const fn initial_interval(
+        mut self,
+        initial_interval: Duration,
+    ) -> RandomizedExponentialBackoffConfig {
+        self.initial_interval = initial_interval;
+        self
+    }
+
+    /// Sets [RandomizedExponentialBackoffConfig::randomization_factor].
+    pub const fn randomization_factor(
+        mut self,
+        randomization_factor: f32,
+    ) -> RandomizedExponentialBackoffConfig {
+        self.randomization_factor = randomization_factor;
+        self
+    }
+
+    /// Sets [RandomizedExponentialBackoffConfig::multiplier].
+    pub const fn multiplier(mut self, multiplier: f32) -> RandomizedExponentialBackoffConfig {
+        self.multiplier = multiplier;
+        self
+    }
+
+    /// Sets [RandomizedExponentialBackoffConfig::max_interval].
+    pub const fn max_interval(
+        mut self,
+        max_interval: Duration,
+    ) -> RandomizedExponentialBackoffConfig {
+        self.max_interval = max_interval;
+        self
+    }
+
+    /// Sets [RandomizedExponentialBackoffConfig::max_elapsed_time].
+    pub const fn max_elapsed_time(
+        mut self,
+        max_elapsed_time: Duration,
+    ) -> RandomizedExponentialBackoffConfig {
+        self.max_elapsed_time = max_elapsed_time;
+        self
+    }
+
+    /// Sets [RandomizedExponentialBackoffConfig::max_retries].
+    pub const fn max_retries(mut self, max_retries: usize) -> RandomizedExponentialBackoffConfig {
+        self.max_retries = max_retries;
+        self
+    }
+
+    /// Sets [RandomizedExponentialBackoffConfig::jitter].
+    pub const fn jitter(mut self, jitter: Jitter) -> RandomizedExponentialBackoffConfig {
+        self.jitter = jitter;
+        self
+    }
+}
+
+#[derive(Debug, smart_default::SmartDefault, Clone, Copy, Deserialize, Serialize, PartialEq)]
+/// Configuration for [Backoff::Polynomial], the schedule of background job systems like Sidekiq.
+///
+/// The `n`-th retry (starting at `0`) is delayed by `base + n^exponent * scale`, plus a random padding of up to
+/// `padding * (n + 1)` with jitter. Delays including the padding never exceed [PolynomialBackoffConfig::max_delay].
+///
+/// Built in code with [PolynomialBackoffConfig::new] and its `const` setters.
+#[non_exhaustive]
+pub struct PolynomialBackoffConfig {
+    /// Base offset of every delay.
+    ///
+    /// Defaults to `15 seconds` - see [defaults::base].
+    #[serde(
+        default = "defaults::base",
+        deserialize_with = "deserialize_duration",
+        serialize_with = "serialize_duration"
+    )]
+    #[default(defaults::base())]
+    pub base: Duration,
+
+    /// Exponent the retry number is raised to.
+    ///
+    /// Defaults to `4.0` - see [defaults::exponent].
+    #[serde(default = "defaults::exponent")]
+    #[default(defaults::exponent())]
+    pub exponent: f32,
+
+    /// Duration the raised retry number is multiplied by.
+    ///
+    /// Defaults to `1 second` - see [defaults::scale].
+    #[serde(
+        default = "defaults::scale",
+        deserialize_with = "deserialize_duration",
+        serialize_with = "serialize_duration"
+    )]
+    #[default(defaults::scale())]
+    pub scale: Duration,
+
+    /// Padding per retry that the random padding is drawn up to.
+    ///
+    /// The padding is the jitter of this strategy: with [Jitter::Disabled], no padding is added.
+    ///
+    /// Defaults to `10 seconds` - see [defaults::padding].
+    #[serde(
+        default = "defaults::padding",
+        deserialize_with = "deserialize_duration",
+        serialize_with = "serialize_duration"
+    )]
+    #[default(defaults::padding())]
+    pub padding: Duration,
+
+    /// Maximum backoff delay, including the padding.
+    ///
+    /// Defaults to `30 seconds` - see [defaults::max_delay].
+    #[serde(
+        default = "defaults::max_delay",
+        deserialize_with = "deserialize_duration",
+        serialize_with = "serialize_duration"
+    )]
+    #[default(defaults::max_delay())]
+    pub max_delay: Duration,
+
+    /// Maximum amount of retries.
+    ///
+    /// Defaults to `4` - see [defaults::max_retries].
+    #[serde(default = "defaults::max_retries")]
+    #[default(defaults::max_retries())]
+    pub max_retries: usize,
+
+    /// Jitter added to the delays as random padding.
+    ///
+    /// Defaults to [Jitter::Random] - see [defaults::jitter].
+    #[serde(default = "defaults::jitter")]
+    #[default(defaults::jitter())]
+    pub jitter: Jitter,
+}
+
+impl PolynomialBackoffConfig {
+    /// Creates a new [PolynomialBackoffConfig] with default values, usable in `const` and `static` items.
+    pub const fn new() -> PolynomialBackoffConfig {
+        PolynomialBackoffConfig {
+            base: defaults::base(),
+            exponent: defaults::exponent(),
+            scale: defaults::scale(),
+            padding: defaults::padding(),
+            max_delay: defaults::max_delay(),
+            max_retries: defaults::max_retries(),
+            jitter: defaults::jitter(),
+        }
+    }
+
+    /// Sets [PolynomialBackoffConfig::base].
+    pub const fn base(mut self, base: Duration) -> PolynomialBackoffConfig {
+        self.base = base;
+        self
+    }
+
+    /// Sets [PolynomialBackoffConfig::exponent].
+    pub const fn exponent(mut self, exponent: f32) -> PolynomialBackoffConfig {
+        self.exponent = exponent;
+        self
+    }
+
+    /// Sets [PolynomialBackoffConfig::scale].
+    pub const fn scale(mut self, scale: Duration) -> PolynomialBackoffConfig {
+        self.scale = scale;
+        self
+    }
+
+    /// Sets [PolynomialBackoffConfig::padding].
+    pub const fn padding(mut self, padding: Duration) -> PolynomialBackoffConfig {
+        self.padding = padding;
+        self
+    }
+
+    /// Sets [PolynomialBackoffConfig::max_delay].
+    pub const fn max_delay(mut self, max_delay: Duration) -> PolynomialBackoffConfig {
+        self.max_delay = max_delay;
+        self
+    }
+
+    /// Sets [PolynomialBackoffConfig::max_retries].
+    pub const fn max_retries(mut self, max_retries: usize) -> PolynomialBackoffConfig {
+        self.max_retries = max_retries;
+        self
+    }
+
+    /// Sets [PolynomialBackoffConfig::jitter].
+    pub const fn jitter(mut self, jitter: Jitter) -> PolynomialBackoffConfig {
+        self.jitter = jitter;
+        self
+    }
+}
+
+#[derive(Debug, smart_default::SmartDefault, Clone, Copy, Deserialize, Serialize, PartialEq)]
+/// Configuration for [Backoff::Random], e.g. to desynchronize polling of a fleet.
+///
+/// Every delay is drawn uniformly from the half-open range `min_delay..max_delay` (see [RandomBackoffConfig::min_delay]
+/// and [RandomBackoffConfig::max_delay]), without growth, or is exactly `min_delay` if both are equal. Deserialization
+/// rejects a `min_delay` above `max_delay`.
+///
+/// Built in code with [RandomBackoffConfig::new] and its `const` setters.
+#[non_exhaustive]
+pub struct RandomBackoffConfig {
+    /// Minimum backoff delay.
+    ///
+    /// Defaults to `100 millis` - see [defaults::min_delay].
+    #[serde(
+        default = "defaults::min_delay",
+        deserialize_with = "deserialize_duration",
+        serialize_with = "serialize_duration"
+    )]
+    #[default(defaults::min_delay())]
+    pub min_delay: Duration,
+
+    /// Maximum backoff delay.
+    ///
+    /// Defaults to `30 seconds` - see [defaults::max_delay].
+    #[serde(
+        default = "defaults::max_delay",
+        deserialize_with = "deserialize_duration",
+        serialize_with = "serialize_duration"
+    )]
+    #[default(defaults::max_delay())]
+    pub max_delay: Duration,
+
+    /// Maximum amount of retries.
+    ///
+    /// Defaults to `4` - see [defaults::max_retries].
+    #[serde(default = "defaults::max_retries")]
+    #[default(defaults::max_retries())]
+    pub max_retries: usize,
+
+    /// Seed of the random delays, which makes them reproducible.
+    ///
+    /// Defaults to no seed - see [defaults::seed].
+    #[serde(default = "defaults::seed")]
+    #[default(defaults::seed())]
+    pub seed: Option<u64>,
+}
+
+impl RandomBackoffConfig {
+    /// Creates a new [RandomBackoffConfig] with default values, usable in `const` and `static` items.
+    pub const fn new() -> RandomBackoffConfig {
+        RandomBackoffConfig {
+            min_delay: defaults::min_delay(),
+            max_delay: defaults::max_delay(),
+            max_retries: defaults::max_retries(),
+            seed: defaults::seed(),
+        }
+    }
+
+    /// Sets [RandomBackoffConfig::min_delay].
+    pub const fn min_delay(mut self, min_delay: Duration) -> RandomBackoffConfig {
+        self.min_delay = min_delay;
+        self
+    }
+
+    /// Sets [RandomBackoffConfig::max_delay].
+    pub const fn max_delay(mut self, max_delay: Duration) -> RandomBackoffConfig {
+        self.max_delay = max_delay;
+        self
+    }
+
+    /// Sets [RandomBackoffConfig::max_retries].
+    pub const fn max_retries(mut self, max_retries: usize) -> RandomBackoffConfig {
+        self.max_retries = max_retries;
+        self
+    }
+
+    /// Sets [RandomBackoffConfig::seed].
+    pub const fn seed(mut self, seed: Option<u64>) -> RandomBackoffConfig {
+        self.seed = seed;
+        self
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+/// Jitter added to the delays of a [Backoff]: a random amount of up to the base delay.
+///
+/// Deserialized from `false` / `"disabled"` for [Jitter::Disabled], `true` / `"random"` for [Jitter::Random], or a seed
+/// (e.g. `42`) for [Jitter::Seeded]. Serialized as `false`, `true` or the seed.
+pub enum Jitter {
+    /// No jitter is added.
+    Disabled,
+
+    /// Jitter is drawn from a randomly seeded generator.
+    Random,
+
+    /// Jitter is drawn from a generator with the given seed, so every [Backoff] yields the same delays.
+    Seeded(u64),
+}
+
+impl Jitter {
+    /// Returns whether jitter is added.
+    pub const fn is_enabled(self) -> bool {
+        !matches!(self, Jitter::Disabled)
+    }
+
+    /// Returns the seed of [Jitter::Seeded].
+    pub const fn seed(self) -> Option<u64> {
+        match self {
+            Jitter::Seeded(seed) => Some(seed),
+            _ => None,
+        }
+    }
+
+    /// Returns [Jitter::Seeded] with `seed` if jitter is enabled, or [Jitter::Disabled] otherwise.
+    pub const fn with_seed(self, seed: u64) -> Jitter {
+        match self {
+            Jitter::Disabled => Jitter::Disabled,
+            _ => Jitter::Seeded(seed),
+        }
+    }
+
+    /// Returns the [Jitter] described by the former `jitter_enabled` and `jitter_seed` fields of the configs.
+    #[deprecated(note = "use the `Jitter` variants directly, e.g. `Jitter::Seeded(seed)`")]
+    pub const fn from_parts(enabled: bool, seed: Option<u64>) -> Jitter {
+        match (enabled, seed) {
+            (false, _) => Jitter::Disabled,
+            (true, None) => Jitter::Random,
+            (true, Some(seed)) => Jitter::Seeded(seed),
+        }
+    }
+}
+
+impl Serialize for Jitter {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match *self {
+            Jitter::Disabled => serializer.serialize_bool(false),
+            Jitter::Random => serializer.serialize_bool(true),
+            Jitter::Seeded(seed) => serializer.serialize_u64(seed),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Jitter {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Jitter, D::Error> {
+        struct JitterVisitor;
+
+        impl serde::de::Visitor<'_> for JitterVisitor {
+            type Value = Jitter;
+
+            fn expecting(&self, f: &mut Formatter) -> std::fmt::Result {
+                f.write_str(
+                    "`false` / `\"disabled\"`, `true` / `\"random\"` or a jitter seed (e.g. `42`)",
+                )
+            }
+
+            fn visit_bool<E: serde::de::Error>(self, v: bool) -> Result<Jitter, E> {
+                Ok(if v { Jitter::Random } else { Jitter::Disabled })
+            }
+
+            fn visit_u64<E: serde::de::Error>(self, v: u64) -> Result<Jitter, E> {
+                Ok(Jitter::Seeded(v))
+            }
+
+            fn visit_i64<E: serde::de::Error>(self, v: i64) -> Result<Jitter, E> {
+                u64::try_from(v)
+                    .map(Jitter::Seeded)
+                    .map_err(|_| E::invalid_value(serde::de::Unexpected::Signed(v), &self))
+            }
+
+            fn visit_str<E: serde::de::Error>(self, v: &str) -> Result<Jitter, E> {
+                match v.trim().to_lowercase().as_str() {
+                    "false" | "disabled" | "none" | "off" => Ok(Jitter::Disabled),
+                    "true" | "random" | "enabled" | "on" => Ok(Jitter::Random),
+                    seed => seed
+                        .parse()
+                        .map(Jitter::Seeded)
+                        .map_err(|_| E::invalid_value(serde::de::Unexpected::Str(v), &self)),
+                }
+            }
+        }
+
+        deserializer.deserialize_any(JitterVisitor)
+    }
+}
 
-    /// Random seed to initialize the random jitter generator.
+impl BackoffConfig {
+    /// Returns this [BackoffConfig] with its jitter seed replaced by `seed`.
     ///
-    /// Defaults to `None` - see [defaults::jitter_seed].
-    #[serde(default = "defaults::jitter_seed")]
-    #[default(defaults::jitter_seed())]
-    pub jitter_seed: Option<u64>,
+    /// Jitter stays enabled or disabled as configured, but every [Backoff] built from the result produces the same
+    /// delays, which makes retries deterministic in tests.
+    pub fn with_jitter_seed(self, seed: u64) -> BackoffConfig {
+        match self {
+            BackoffConfig::Constant(config) => BackoffConfig::Constant(ConstantBackoffConfig {
+                jitter: config.jitter.with_seed(seed),
+                ..config
+            }),
+            BackoffConfig::Exponential(config) => {
+                BackoffConfig::Exponential(ExponentialBackoffConfig {
+                    jitter: config.jitter.with_seed(seed),
+                    ..config
+                })
+            }
+            BackoffConfig::Fibonacci(config) => BackoffConfig::Fibonacci(FibonacciBackoffConfig {
+                jitter: config.jitter.with_seed(seed),
+                ..config
+            }),
+            BackoffConfig::Adaptive(config) => BackoffConfig::Adaptive(AdaptiveBackoffConfig {
+                jitter: config.jitter.with_seed(seed),
+                ..config
+            }),
+            BackoffConfig::RandomizedExponential(config) => {
+                BackoffConfig::RandomizedExponential(RandomizedExponentialBackoffConfig {
+                    jitter: config.jitter.with_seed(seed),
+                    ..config
+                })
+            }
+            BackoffConfig::Polynomial(config) => {
+                BackoffConfig::Polynomial(PolynomialBackoffConfig {
+                    jitter: config.jitter.with_seed(seed),
+                    ..config
+                })
+            }
+            BackoffConfig::Random(config) => BackoffConfig::Random(RandomBackoffConfig {
+                seed: Some(seed),
+                ..config
+            }),
+            BackoffConfig::NoBackoff => BackoffConfig::NoBackoff,
+        }
+    }
 }
 
-#[derive(Debug, smart_default::SmartDefault, Clone, Copy, Deserialize, PartialEq)]
-/// Configuration for [Backoff::Fibonacci].
-pub struct FibonacciBackoffConfig {
-    /// Initial backoff delay.
-    ///
-    /// Defaults to `500 millis` - see [defaults::delay].
-    #[serde(default = "defaults::delay", deserialize_with = "deserialize_duration")]
-    #[default(defaults::delay())]
-    pub initial_delay: Duration,
+/// Concise, stable summary of the policy, e.g. `exponential 100ms×2.0 up to 30s, 5 retries, ≤60s total, jitter`.
+impl Display for BackoffConfig {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            BackoffConfig::Constant(c) => {
+                write!(f, "constant {:?}", c.delay)?;
+                write_retries(f, c.max_retries)?;
+                write_jitter(f, c.jitter)
+            }
 
-    /// Maximum backoff delay.
-    ///
-    /// Defaults to `30 seconds` - see [defaults::max_delay].
-    #[serde(
-        default = "defaults::max_delay",
-        deserialize_with = "deserialize_duration"
-    )]
-    #[default(defaults::max_delay())]
-    pub max_delay: Duration,
+            BackoffConfig::Exponential(c) => {
+                write!(
+                    f,
+                    "exponential {:?}×{:?} up to {:?}",
+                    c.initial_delay, c.factor, c.max_delay
+                )?;
+                write_retries(f, c.max_retries)?;
+                if c.max_total_delay != Duration::MAX {
+                    write!(f, ", ≤{:?} total", c.max_total_delay)?;
+                }
+                write_jitter(f, c.jitter)
+            }
 
-    /// Maximum amount of retries.
-    ///
-    /// Defaults to `4` - see [defaults::max_retries].
-    #[serde(default = "defaults::max_retries")]
-    #[default(defaults::max_retries())]
-    pub max_retries: usize,
+            BackoffConfig::Fibonacci(c) => {
+                write!(f, "fibonacci {:?} up to {:?}", c.initial_delay, c.max_delay)?;
+                write_retries(f, c.max_retries)?;
+                write_jitter(f, c.jitter)
+            }
 
-    /// Whether jitter is enabled.
-    ///
-    /// Defaults to `true` - see [defaults::jitter_enabled].
-    #[serde(default = "defaults::jitter_enabled")]
-    #[default(defaults::jitter_enabled())]
-    pub jitter_enabled: bool,
+            BackoffConfig::Adaptive(c) => {
+                write!(
+                    f,
+                    "adaptive {:?}×{:?} within {:?}..{:?}, -{:?} on success",
+                    c.initial_delay, c.increase_factor, c.min_delay, c.max_delay, c.decrease_step
+                )?;
+                write_retries(f, c.max_retries)?;
+                write_jitter(f, c.jitter)
+            }
 
-    /// Random seed to initialize the random jitter generator.
-    ///
-    /// Defaults to `None` - see [defaults::jitter_seed].
-    #[serde(default = "defaults::jitter_seed")]
-    #[default(defaults::jitter_seed())]
-    pub jitter_seed: Option<u64>,
+            BackoffConfig::RandomizedExponential(c) => {
+                write!(
+                    f,
+                    "randomized exponential {:?}×{:?} ±{:?} up to {:?}",
+                    c.initial_interval, c.multiplier, c.randomization_factor, c.max_interval
+                )?;
+                write_retries(f, c.max_retries)?;
+                if c.max_elapsed_time != Duration::MAX {
+                    write!(f, ", ≤{:?} total", c.max_elapsed_time)?;
+                }
+                write_jitter(f, c.jitter)
+            }
+
+            BackoffConfig::Polynomial(c) => {
+                write!(
+                    f,
+                    "polynomial {:?} + n^{:?}×{:?}",
+                    c.base, c.exponent, c.scale
+                )?;
+                if c.max_delay != Duration::MAX {
+                    write!(f, " up to {:?}", c.max_delay)?;
+                }
+                write_retries(f, c.max_retries)?;
+                match c.jitter {
+                    Jitter::Disabled => Ok(()),
+                    jitter => {
+                        write!(f, ", +{:?}×(n+1) padding", c.padding)?;
+                        write_jitter(f, jitter)
+                    }
+                }
+            }
+
+            BackoffConfig::Random(c) => {
+                write!(f, "random {:?}..{:?}", c.min_delay, c.max_delay)?;
+                write_retries(f, c.max_retries)?;
+                match c.seed {
+                    Some(seed) => write!(f, ", seed {seed}"),
+                    None => Ok(()),
+                }
+            }
+
+            BackoffConfig::NoBackoff => write!(f, "no backoff"),
+        }
+    }
+}
+
+fn write_retries(f: &mut Formatter<'_>, max_retries: usize) -> std::fmt::Result {
+    match max_retries {
+        1 => write!(f, ", 1 retry"),
+        usize::MAX => write!(f, ", unlimited retries"),
+        max_retries => write!(f, ", {max_retries} retries"),
+    }
+}
+
+fn write_jitter(f: &mut Formatter<'_>, jitter: Jitter) -> std::fmt::Result {
+    match jitter {
+        Jitter::Disabled => Ok(()),
+        Jitter::Random => write!(f, ", jitter"),
+        Jitter::Seeded(seed) => write!(f, ", jitter (seed {seed})"),
+    }
 }
 
 impl backon::BackoffBuilder for BackoffConfig {
@@ -176,83 +1115,32 @@ impl backon::BackoffBuilder for BackoffConfig {
 
     fn build(self) -> Backoff {
         match self {
-            BackoffConfig::Constant(ConstantBackoffConfig {
-                delay,
-                max_retries,
-                jitter_enabled,
-                jitter_seed,
-            }) => {
-                let mut builder = backon::ConstantBuilder::new()
-                    .with_delay(delay)
-                    .with_max_times(max_retries);
-
-                if jitter_enabled {
-                    builder = builder.with_jitter();
-                }
-
-                if let Some(jitter_seed) = jitter_seed {
-                    builder = builder.with_jitter_seed(jitter_seed);
-                }
-
-                Backoff::Constant(builder.build())
-            }
-
-            BackoffConfig::Exponential(ExponentialBackoffConfig {
-                initial_delay,
-                factor,
-                max_delay,
-                max_retries,
-                max_total_delay,
-                jitter_enabled,
-                jitter_seed,
-            }) => {
-                let mut builder = backon::ExponentialBuilder::new()
-                    .with_min_delay(initial_delay)
-                    .with_factor(factor)
-                    .with_max_delay(max_delay)
-                    .with_max_times(max_retries)
-                    .with_total_delay(Some(max_total_delay));
-
-                if jitter_enabled {
-                    builder = builder.with_jitter();
-                }
-
-                if let Some(jitter_seed) = jitter_seed {
-                    builder = builder.with_jitter_seed(jitter_seed);
-                }
+            BackoffConfig::Constant(config) => Backoff::Constant(ConstantBackoff::new(config)),
 
-                Backoff::Exponential(builder.build())
+            BackoffConfig::Exponential(config) => {
+                Backoff::Exponential(ExponentialBackoff::new(config))
             }
 
-            BackoffConfig::Fibonacci(FibonacciBackoffConfig {
-                initial_delay,
-                max_delay,
-                max_retries,
-                jitter_enabled,
-                jitter_seed,
-            }) => {
-                let mut builder = backon::FibonacciBuilder::new()
-                    .with_min_delay(initial_delay)
-                    .with_max_delay(max_delay)
-                    .with_max_times(max_retries);
+            BackoffConfig::Fibonacci(config) => Backoff::Fibonacci(FibonacciBackoff::new(config)),
 
-                if jitter_enabled {
-                    builder = builder.with_jitter();
-                }
+            BackoffConfig::Adaptive(config) => AdaptiveState::new(config).backoff(),
 
-                if let Some(jitter_seed) = jitter_seed {
-                    builder = builder.with_jitter_seed(jitter_seed);
-                }
+            BackoffConfig::RandomizedExponential(config) => {
+                Backoff::RandomizedExponential(RandomizedExponentialBackoff::new(config))
+            }
 
-                Backoff::Fibonacci(builder.build())
+            BackoffConfig::Polynomial(config) => {
+                Backoff::Polynomial(PolynomialBackoff::new(config))
             }
 
+            BackoffConfig::Random(config) => Backoff::Random(RandomBackoff::new(config)),
+
             BackoffConfig::NoBackoff => Backoff::NoBackoff,
         }
     }
 }
 
-/// Contains the defaults used by the [crate::BackoffConfig].
+/// Contains the defaults used by the [crate::BackoffConfig] and the other configs of this crate.
 pub mod defaults {
     use std::time::Duration;
 
@@ -266,16 +1154,28 @@ pub mod defaults {
         4
     }
 
+    /// Default value for jitter: random, without a fixed seed.
+    pub const fn jitter() -> crate::Jitter {
+        crate::Jitter::Random
+    }
+
     /// Default value whether jitter is enabled.
+    #[deprecated(note = "use `defaults::jitter` instead")]
     pub const fn jitter_enabled() -> bool {
         true
     }
 
     /// Default value for jitter seed.
+    #[deprecated(note = "use `defaults::jitter` instead")]
     pub const fn jitter_seed() -> Option<u64> {
         None
     }
 
+    /// Default value for the seed of random delays: none, i.e. different delays for every backoff.
+    pub const fn seed() -> Option<u64> {
+        None
+    }
+
     /// Default value for backoff factor.
     pub const fn factor() -> f32 {
         2.0
@@ -290,6 +1190,122 @@ pub mod defaults {
     pub const fn max_total_delay() -> Duration {
         Duration::from_secs(60)
     }
+
+    /// Default value for unlimited max retries.
+    pub const fn unlimited_retries() -> usize {
+        usize::MAX
+    }
+
+    /// Default value for randomized exponential backoff randomization factor.
+    pub const fn randomization_factor() -> f32 {
+        0.5
+    }
+
+    /// Default value for randomized exponential backoff multiplier.
+    pub const fn multiplier() -> f32 {
+        1.5
+    }
+
+    /// Default value for randomized exponential backoff max interval.
+    pub const fn max_interval() -> Duration {
+        Duration::from_secs(60)
+    }
+
+    /// Default value for randomized exponential backoff max elapsed time.
+    pub const fn max_elapsed_time() -> Duration {
+        Duration::from_secs(15 * 60)
+    }
+
+    /// Default value for polynomial backoff base offset.
+    pub const fn base() -> Duration {
+        Duration::from_secs(15)
+    }
+
+    /// Default value for polynomial backoff exponent.
+    pub const fn exponent() -> f32 {
+        4.0
+    }
+
+    /// Default value for polynomial backoff scale.
+    pub const fn scale() -> Duration {
+        Duration::from_secs(1)
+    }
+
+    /// Default value for polynomial backoff padding per retry.
+    pub const fn padding() -> Duration {
+        Duration::from_secs(10)
+    }
+
+    /// Default value for min backoff delay.
+    pub const fn min_delay() -> Duration {
+        Duration::from_millis(100)
+    }
+
+    /// Default value for adaptive backoff decrease step.
+    pub const fn decrease_step() -> Duration {
+        Duration::from_millis(100)
+    }
+
+    /// Default value for retry budget ratio of retries to requests.
+    pub const fn retry_ratio() -> f32 {
+        0.2
+    }
+
+    /// Default value for retry budget min retries per second.
+    pub const fn min_retries_per_second() -> u32 {
+        10
+    }
+
+    /// Default value for retry budget time-to-live.
+    pub const fn ttl() -> Duration {
+        Duration::from_secs(10)
+    }
+
+    /// Default value for circuit breaker failure rate threshold.
+    pub const fn failure_rate_threshold() -> f32 {
+        0.5
+    }
+
+    /// Default value for circuit breaker sliding window size.
+    pub const fn window_size() -> usize {
+        100
+    }
+
+    /// Default value for circuit breaker open-state cooldown: exponential backoff from `5 seconds` up to `60 seconds`.
+    pub const fn open_cooldown() -> crate::BackoffConfig {
+        crate::BackoffConfig::Exponential(crate::ExponentialBackoffConfig {
+            initial_delay: Duration::from_secs(5),
+            factor: factor(),
+            max_delay: Duration::from_secs(60),
+            max_retries: max_retries(),
+            max_total_delay: Duration::MAX,
+            jitter: jitter(),
+        })
+    }
+
+    /// Default value for circuit breaker half-open probes.
+    pub const fn half_open_probes() -> usize {
+        5
+    }
+
+    /// Default value for hedge delay: constant `100 millis` without jitter.
+    pub const fn hedge_delay() -> crate::BackoffConfig {
+        crate::BackoffConfig::Constant(crate::ConstantBackoffConfig {
+            delay: Duration::from_millis(100),
+            max_retries: max_retries(),
+            jitter: crate::Jitter::Disabled,
+        })
+    }
+
+    /// Default value for hedging max total attempts.
+    pub const fn max_total_attempts() -> usize {
+        3
+    }
+
+    /// Default value for hedging non-fatal statuses.
+    pub const fn non_fatal_statuses() -> Vec<String> {
+        Vec::new()
+    }
 }
 
 #[cfg(test)]
@@ -302,8 +1318,7 @@ mod tests {
         let constant_config = ConstantBackoffConfig {
             delay: Duration::from_secs(1),
             max_retries: 3,
-            jitter_enabled: false,
-            jitter_seed: None,
+            jitter: Jitter::Disabled,
         };
         let backoff_config: BackoffConfig = constant_config.into();
         assert_eq!(backoff_config, BackoffConfig::Constant(constant_config));
@@ -314,8 +1329,7 @@ mod tests {
             max_delay: Duration::from_millis(800),
             max_retries: 5,
             max_total_delay: Duration::from_secs(1000),
-            jitter_enabled: false,
-            jitter_seed: None,
+            jitter: Jitter::Disabled,
         };
         let backoff_config: BackoffConfig = exponential_config.into();
         assert_eq!(
@@ -327,11 +1341,22 @@ mod tests {
             initial_delay: Duration::from_millis(100),
             max_delay: Duration::from_millis(800),
             max_retries: 5,
-            jitter_enabled: false,
-            jitter_seed: None,
+            jitter: Jitter::Disabled,
         };
         let backoff_config: BackoffConfig = fibonacci_config.into();
         assert_eq!(backoff_config, BackoffConfig::Fibonacci(fibonacci_config));
+
+        let adaptive_config = AdaptiveBackoffConfig {
+            initial_delay: Duration::from_millis(100),
+            min_delay: Duration::from_millis(50),
+            max_delay: Duration::from_millis(800),
+            increase_factor: 2_f32,
+            decrease_step: Duration::from_millis(50),
+            max_retries: 5,
+            jitter: Jitter::Disabled,
+        };
+        let backoff_config: BackoffConfig = adaptive_config.into();
+        assert_eq!(backoff_config, BackoffConfig::Adaptive(adaptive_config));
     }
 
     #[test]
@@ -342,8 +1367,7 @@ mod tests {
             ConstantBackoffConfig {
                 delay: defaults::delay(),
                 max_retries: defaults::max_retries(),
-                jitter_enabled: defaults::jitter_enabled(),
-                jitter_seed: defaults::jitter_seed(),
+                jitter: defaults::jitter(),
             }
         );
 
@@ -356,8 +1380,7 @@ mod tests {
                 max_delay: defaults::max_delay(),
                 max_retries: defaults::max_retries(),
                 max_total_delay: defaults::max_total_delay(),
-                jitter_enabled: defaults::jitter_enabled(),
-                jitter_seed: defaults::jitter_seed(),
+                jitter: defaults::jitter(),
             }
         );
 
@@ -368,8 +1391,225 @@ mod tests {
                 initial_delay: defaults::delay(),
                 max_delay: defaults::max_delay(),
                 max_retries: defaults::max_retries(),
-                jitter_enabled: defaults::jitter_enabled(),
-                jitter_seed: defaults::jitter_seed(),
+                jitter: defaults::jitter(),
+            }
+        );
+
+        let adaptive = AdaptiveBackoffConfig::default();
+        assert_eq!(
+            adaptive,
+            AdaptiveBackoffConfig {
+                initial_delay: defaults::delay(),
+                min_delay: defaults::min_delay(),
+                max_delay: defaults::max_delay(),
+                increase_factor: defaults::factor(),
+                decrease_step: defaults::decrease_step(),
+                max_retries: defaults::max_retries(),
+                jitter: defaults::jitter(),
+            }
+        );
+
+        let randomized_exponential = RandomizedExponentialBackoffConfig::default();
+        assert_eq!(
+            randomized_exponential,
+            RandomizedExponentialBackoffConfig {
+                initial_interval: defaults::delay(),
+                randomization_factor: defaults::randomization_factor(),
+                multiplier: defaults::multiplier(),
+                max_interval: defaults::max_interval(),
+                max_elapsed_time: defaults::max_elapsed_time(),
+                max_retries: defaults::unlimited_retries(),
+                jitter: defaults::jitter(),
+            }
+        );
+
+        let polynomial = PolynomialBackoffConfig::default();
+        assert_eq!(
+            polynomial,
+            PolynomialBackoffConfig {
+                base: defaults::base(),
+                exponent: defaults::exponent(),
+                scale: defaults::scale(),
+                padding: defaults::padding(),
+                max_delay: defaults::max_delay(),
+                max_retries: defaults::max_retries(),
+                jitter: defaults::jitter(),
+            }
+        );
+
+        let random = RandomBackoffConfig::default();
+        assert_eq!(
+            random,
+            RandomBackoffConfig {
+                min_delay: defaults::min_delay(),
+                max_delay: defaults::max_delay(),
+                max_retries: defaults::max_retries(),
+                seed: defaults::seed(),
+            }
+        );
+    }
+
+    #[test]
+    #[allow(deprecated)]
+    fn deprecated_jitter_parts() {
+        assert_eq!(
+            Jitter::from_parts(defaults::jitter_enabled(), defaults::jitter_seed()),
+            defaults::jitter()
+        );
+        assert_eq!(Jitter::from_parts(false, Some(42)), Jitter::Disabled);
+        assert_eq!(Jitter::from_parts(true, Some(42)), Jitter::Seeded(42));
+    }
+
+    #[test]
+    fn builders() {
+        const CONSTANT: ConstantBackoffConfig = ConstantBackoffConfig::new()
+            .delay(Duration::from_secs(1))
+            .max_retries(3)
+            .jitter(Jitter::Seeded(7));
+        assert_eq!(
+            ConstantBackoffConfig::new(),
+            ConstantBackoffConfig::default()
+        );
+        assert_eq!(
+            CONSTANT,
+            ConstantBackoffConfig {
+                delay: Duration::from_secs(1),
+                max_retries: 3,
+                jitter: Jitter::Seeded(7),
+            }
+        );
+
+        const EXPONENTIAL: ExponentialBackoffConfig = ExponentialBackoffConfig::new()
+            .initial_delay(Duration::from_millis(100))
+            .factor(3.0)
+            .max_delay(Duration::from_secs(10))
+            .max_retries(5)
+            .max_total_delay(Duration::from_secs(20))
+            .jitter(Jitter::Disabled);
+        assert_eq!(
+            ExponentialBackoffConfig::new(),
+            ExponentialBackoffConfig::default()
+        );
+        assert_eq!(
+            EXPONENTIAL,
+            ExponentialBackoffConfig {
+                initial_delay: Duration::from_millis(100),
+                factor: 3.0,
+                max_delay: Duration::from_secs(10),
+                max_retries: 5,
+                max_total_delay: Duration::from_secs(20),
+                jitter: Jitter::Disabled,
+            }
+        );
+
+        const FIBONACCI: FibonacciBackoffConfig = FibonacciBackoffConfig::new()
+            .initial_delay(Duration::from_millis(100))
+            .max_delay(Duration::from_secs(10))
+            .max_retries(5)
+            .jitter(Jitter::Random);
+        assert_eq!(
+            FibonacciBackoffConfig::new(),
+            FibonacciBackoffConfig::default()
+        );
+        assert_eq!(
+            FIBONACCI,
+            FibonacciBackoffConfig {
+                initial_delay: Duration::from_millis(100),
+                max_delay: Duration::from_secs(10),
+                max_retries: 5,
+                jitter: Jitter::Random,
+            }
+        );
+
+        const ADAPTIVE: AdaptiveBackoffConfig = AdaptiveBackoffConfig::new()
+            .initial_delay(Duration::from_millis(200))
+            .min_delay(Duration::from_millis(50))
+            .max_delay(Duration::from_secs(10))
+            .increase_factor(1.5)
+            .decrease_step(Duration::from_millis(25))
+            .max_retries(6)
+            .jitter(Jitter::Disabled);
+        assert_eq!(
+            AdaptiveBackoffConfig::new(),
+            AdaptiveBackoffConfig::default()
+        );
+        assert_eq!(
+            ADAPTIVE,
+            AdaptiveBackoffConfig {
+                initial_delay: Duration::from_millis(200),
+                min_delay: Duration::from_millis(50),
+                max_delay: Duration::from_secs(10),
+                increase_factor: 1.5,
+                decrease_step: Duration::from_millis(25),
+                max_retries: 6,
+                jitter: Jitter::Disabled,
+            }
+        );
+
+        static RANDOMIZED_EXPONENTIAL: RandomizedExponentialBackoffConfig =
+            RandomizedExponentialBackoffConfig::new()
+                .initial_interval(Duration::from_millis(250))
+                .randomization_factor(0.25)
+                .multiplier(2.0)
+                .max_interval(Duration::from_secs(20))
+                .max_elapsed_time(Duration::from_secs(300))
+                .max_retries(8)
+                .jitter(Jitter::Seeded(3));
+        assert_eq!(
+            RandomizedExponentialBackoffConfig::new(),
+            RandomizedExponentialBackoffConfig::default()
+        );
+        assert_eq!(
+            RANDOMIZED_EXPONENTIAL,
+            RandomizedExponentialBackoffConfig {
+                initial_interval: Duration::from_millis(250),
+                randomization_factor: 0.25,
+                multiplier: 2.0,
+                max_interval: Duration::from_secs(20),
+                max_elapsed_time: Duration::from_secs(300),
+                max_retries: 8,
+                jitter: Jitter::Seeded(3),
+            }
+        );
+
+        static POLYNOMIAL: PolynomialBackoffConfig = PolynomialBackoffConfig::new()
+            .base(Duration::from_secs(1))
+            .exponent(2.0)
+            .scale(Duration::from_millis(500))
+            .padding(Duration::from_millis(100))
+            .max_delay(Duration::from_secs(60))
+            .max_retries(10)
+            .jitter(Jitter::Random);
+        assert_eq!(
+            PolynomialBackoffConfig::new(),
+            PolynomialBackoffConfig::default()
+        );
+        assert_eq!(
+            POLYNOMIAL,
+            PolynomialBackoffConfig {
+                base: Duration::from_secs(1),
+                exponent: 2.0,
+                scale: Duration::from_millis(500),
+                padding: Duration::from_millis(100),
+                max_delay: Duration::from_secs(60),
+                max_retries: 10,
+                jitter: Jitter::Random,
+            }
+        );
+
+        static RANDOM: RandomBackoffConfig = RandomBackoffConfig::new()
+            .min_delay(Duration::from_millis(200))
+            .max_delay(Duration::from_secs(2))
+            .max_retries(7)
+            .seed(Some(11));
+        assert_eq!(RandomBackoffConfig::new(), RandomBackoffConfig::default());
+        assert_eq!(
+            RANDOM,
+            RandomBackoffConfig {
+                min_delay: Duration::from_millis(200),
+                max_delay: Duration::from_secs(2),
+                max_retries: 7,
+                seed: Some(11),
             }
         );
     }
@@ -379,8 +1619,7 @@ mod tests {
         let config = BackoffConfig::Constant(ConstantBackoffConfig {
             delay: Duration::from_secs(1),
             max_retries: 3,
-            jitter_enabled: false,
-            jitter_seed: None,
+            jitter: Jitter::Disabled,
         });
 
         let backoff = config.build();
@@ -400,8 +1639,7 @@ mod tests {
         let config = BackoffConfig::Constant(ConstantBackoffConfig {
             delay: Duration::from_secs(1),
             max_retries: 3,
-            jitter_enabled: true,
-            jitter_seed: Some(0),
+            jitter: Jitter::Seeded(0),
         });
 
         let backoff = config.build();
@@ -424,8 +1662,7 @@ mod tests {
             max_delay: Duration::from_millis(800),
             max_retries: 5,
             max_total_delay: Duration::from_secs(1000),
-            jitter_enabled: false,
-            jitter_seed: None,
+            jitter: Jitter::Disabled,
         });
 
         let backoff = config.build();
@@ -448,8 +1685,7 @@ mod tests {
             max_delay: Duration::from_millis(800),
             max_retries: 5,
             max_total_delay: Duration::from_secs(1000),
-            jitter_enabled: true,
-            jitter_seed: Some(0),
+            jitter: Jitter::Seeded(0),
         });
 
         let backoff = config.build();
@@ -472,8 +1708,7 @@ mod tests {
             max_delay: Duration::from_millis(800),
             max_retries: 5,
             max_total_delay: Duration::from_millis(1500 + 1),
-            jitter_enabled: false,
-            jitter_seed: None,
+            jitter: Jitter::Disabled,
         });
 
         let backoff = config.build();
@@ -494,8 +1729,7 @@ mod tests {
             initial_delay: Duration::from_millis(100),
             max_delay: Duration::from_millis(800),
             max_retries: 5,
-            jitter_enabled: false,
-            jitter_seed: None,
+            jitter: Jitter::Disabled,
         });
 
         let backoff = config.build();
@@ -516,8 +1750,7 @@ mod tests {
             initial_delay: Duration::from_millis(100),
             max_delay: Duration::from_millis(800),
             max_retries: 5,
-            jitter_enabled: true,
-            jitter_seed: Some(0),
+            jitter: Jitter::Seeded(0),
         });
 
         let backoff = config.build();
@@ -532,6 +1765,62 @@ mod tests {
         );
     }
 
+    #[test]
+    fn adaptive_backoff_config_to_backoff() {
+        let config = BackoffConfig::Adaptive(AdaptiveBackoffConfig {
+            initial_delay: Duration::from_millis(100),
+            min_delay: Duration::from_millis(50),
+            max_delay: Duration::from_millis(800),
+            increase_factor: 2_f32,
+            decrease_step: Duration::from_millis(50),
+            max_retries: 6,
+            jitter: Jitter::Disabled,
+        });
+
+        let backoff = config.build();
+        assert!(matches!(backoff, Backoff::Adaptive(_)));
+
+        assert_eq!(
+            backoff
+                .take(100)
+                .map(|duration| duration.as_millis())
+                .collect::<Vec<_>>(),
+            vec![100, 200, 400, 800, 800, 800]
+        );
+    }
+
+    #[test]
+    fn adaptive_backoff_config_to_backoff_with_jitter() {
+        let config = AdaptiveBackoffConfig {
+            initial_delay: Duration::from_millis(100),
+            min_delay: Duration::from_millis(50),
+            max_delay: Duration::from_millis(800),
+            increase_factor: 2_f32,
+            decrease_step: Duration::from_millis(50),
+            max_retries: 5,
+            jitter: Jitter::Seeded(0),
+        };
+        let state = AdaptiveState::new(config);
+        state.record_failure();
+        state.record_failure();
+        state.record_success();
+
+        let delays = state
+            .backoff()
+            .map(|duration| duration.as_millis())
+            .collect::<Vec<_>>();
+
+        // The jitter is added on top of the shared base delay of 350ms, which grows per retry up to the max delay.
+        for (delay, base) in delays.iter().zip([350, 700, 800, 800, 800]) {
+            assert!((base..base * 2).contains(delay), "{delay} for {base}");
+        }
+        assert_eq!(
+            delays,
+            state.backoff().map(|d| d.as_millis()).collect::<Vec<_>>()
+        );
+        assert_eq!(state.base_delay(), Duration::from_millis(350));
+    }
+
     #[test]
     fn no_backoff_backoff_config_to_backoff() {
         let config = BackoffConfig::NoBackoff;
@@ -541,4 +1830,86 @@ mod tests {
 
         assert!(backoff.next().is_none());
     }
+
+    #[test]
+    fn display() {
+        let exponential = BackoffConfig::Exponential(ExponentialBackoffConfig {
+            initial_delay: Duration::from_millis(100),
+            factor: 2_f32,
+            max_delay: Duration::from_secs(30),
+            max_retries: 5,
+            max_total_delay: Duration::from_secs(60),
+            jitter: Jitter::Random,
+        });
+        assert_eq!(
+            exponential.to_string(),
+            "exponential 100ms×2.0 up to 30s, 5 retries, ≤60s total, jitter"
+        );
+
+        let constant = BackoffConfig::Constant(ConstantBackoffConfig {
+            delay: Duration::from_millis(1500),
+            max_retries: 1,
+            jitter: Jitter::Disabled,
+        });
+        assert_eq!(constant.to_string(), "constant 1.5s, 1 retry");
+
+        let fibonacci = BackoffConfig::Fibonacci(FibonacciBackoffConfig {
+            max_retries: usize::MAX,
+            jitter: Jitter::Seeded(42),
+            ..Default::default()
+        });
+        assert_eq!(
+            fibonacci.to_string(),
+            "fibonacci 500ms up to 30s, unlimited retries, jitter (seed 42)"
+        );
+
+        let adaptive = BackoffConfig::Adaptive(AdaptiveBackoffConfig {
+            jitter: Jitter::Disabled,
+            ..Default::default()
+        });
+        assert_eq!(
+            adaptive.to_string(),
+            "adaptive 500ms×2.0 within 100ms..30s, -100ms on success, 4 retries"
+        );
+
+        let unbounded = BackoffConfig::Exponential(ExponentialBackoffConfig {
+            max_total_delay: Duration::MAX,
+            jitter: Jitter::Disabled,
+            ..Default::default()
+        });
+        assert_eq!(
+            unbounded.to_string(),
+            "exponential 500ms×2.0 up to 30s, 4 retries"
+        );
+
+        let randomized = BackoffConfig::RandomizedExponential(Default::default());
+        assert_eq!(
+            randomized.to_string(),
+            "randomized exponential 500ms×1.5 ±0.5 up to 60s, unlimited retries, ≤900s total, jitter"
+        );
+
+        let polynomial = BackoffConfig::Polynomial(Default::default());
+        assert_eq!(
+            polynomial.to_string(),
+            "polynomial 15s + n^4.0×1s up to 30s, 4 retries, +10s×(n+1) padding, jitter"
+        );
+
+        let capped = BackoffConfig::Polynomial(PolynomialBackoffConfig {
+            max_delay: Duration::from_secs(3600),
+            jitter: Jitter::Disabled,
+            ..Default::default()
+        });
+        assert_eq!(
+            capped.to_string(),
+            "polynomial 15s + n^4.0×1s up to 3600s, 4 retries"
+        );
+
+        let random = BackoffConfig::Random(RandomBackoffConfig {
+            seed: Some(7),
+            ..Default::default()
+        });
+        assert_eq!(random.to_string(), "random 100ms..30s, 4 retries, seed 7");
+
+        assert_eq!(BackoffConfig::NoBackoff.to_string(), "no backoff");
+    }
 }
@@ -0,0 +1,519 @@
+//! `backoff-config` command-line tool: lints and previews the [BackoffConfig]s of a config file or env.
+//!
+//! Every section with a `strategy` key, or every section given via `--path`, is deserialized as a
+//! [CheckedBackoffConfig] and validated with
+//! [BackoffConfig::validate], reporting keys that are not valid for the `strategy` as problems. Valid configs are printed as a table of their retry schedule - see
+//! [BackoffConfig::delay_bounds], limited to the first [MAX_ROWS] retries. Exits with `1` if any config is invalid or exceeds the `--budget`, and with `2` if
+//! the config cannot be loaded at all.
+
+use backoff_config::*;
+use serde::Deserialize;
+use serde_json::{Map, Value};
+use std::fmt::Write;
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+use std::time::Duration;
+
+const USAGE: &str = "\
+Usage:
+    backoff-config [--budget <DURATION>] [--path <PATH>]... <FILE>
+    backoff-config [--budget <DURATION>] [--path <PATH>]... --env <PREFIX> [--separator <SEPARATOR>]
+
+Lints and previews every backoff config (every section with a `strategy` key) of a TOML / JSON / YAML file, or of the
+environment variables starting with <PREFIX>, nested by <SEPARATOR> (`__` by default).
+
+Options:
+    --budget <DURATION>        Fail if the worst case total delay of a config exceeds DURATION, e.g. `30s`.
+    --path <PATH>              Lint only the config at the `.`-separated PATH, e.g. `clients.0.backoff`, including
+                               scalar shorthands such as `backoff = \"500ms\"`. May be repeated.
+    --env <PREFIX>             Read environment variables starting with PREFIX, e.g. `CONFIG__`, instead of a file.
+    --separator <SEPARATOR>    Separator of nested environment variable keys. Defaults to `__`.
+    -h, --help                 Print this help.";
+
+/// Amount of retries printed in a schedule table before the remaining ones are summarized.
+const MAX_ROWS: usize = 25;
+
+fn main() -> ExitCode {
+    let args = match Args::parse(std::env::args().skip(1)) {
+        Ok(Some(args)) => args,
+        Ok(None) => {
+            println!("{USAGE}");
+            return ExitCode::SUCCESS;
+        }
+        Err(e) => {
+            eprintln!("error: {e}\n\n{USAGE}");
+            return ExitCode::from(2);
+        }
+    };
+
+    let value = match &args.source {
+        Source::File(path) => load_file(path),
+        Source::Env { prefix, separator } => Ok(load_env(std::env::vars(), prefix, separator)),
+    };
+    let value = match value {
+        Ok(value) => value,
+        Err(e) => {
+            eprintln!("error: {e}");
+            return ExitCode::from(2);
+        }
+    };
+
+    let sections = if args.paths.is_empty() {
+        find_sections(&value)
+    } else {
+        match select_sections(&value, &args.paths) {
+            Ok(sections) => sections,
+            Err(e) => {
+                eprintln!("error: {e}");
+                return ExitCode::from(2);
+            }
+        }
+    };
+    if sections.is_empty() {
+        eprintln!(
+            "error: no backoff configs found, expected sections with a `strategy` key, or `--path` for scalar \
+             shorthands"
+        );
+        return ExitCode::FAILURE;
+    }
+
+    let mut problems = 0;
+    for (path, section) in &sections {
+        let report = lint(path, section, args.budget);
+        print!("{}", report.output);
+        problems += report.problems;
+    }
+
+    println!(
+        "{} backoff config(s) checked, {problems} problem(s) found",
+        sections.len()
+    );
+
+    if problems == 0 {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    }
+}
+
+#[derive(Debug, PartialEq)]
+/// Command-line arguments.
+struct Args {
+    source: Source,
+    budget: Option<Duration>,
+    paths: Vec<String>,
+}
+
+#[derive(Debug, PartialEq)]
+/// Where the configs are loaded from.
+enum Source {
+    File(PathBuf),
+    Env { prefix: String, separator: String },
+}
+
+impl Args {
+    /// Parses the command-line arguments, returning `None` if help was requested.
+    fn parse(args: impl IntoIterator<Item = String>) -> Result<Option<Args>, String> {
+        let mut args = args.into_iter();
+        let mut file = None;
+        let mut prefix = None;
+        let mut separator = None;
+        let mut budget = None;
+        let mut paths = Vec::new();
+
+        while let Some(arg) = args.next() {
+            let mut value = |name: &str| args.next().ok_or(format!("`{name}` requires a value"));
+
+            match arg.as_str() {
+                "-h" | "--help" => return Ok(None),
+                "--budget" => {
+                    let raw = value("--budget")?;
+                    let parsed =
+                        parse_duration(&raw).map_err(|e| format!("invalid `--budget`: {e}"))?;
+                    budget = Some(parsed);
+                }
+                "--path" => paths.push(value("--path")?),
+                "--env" => prefix = Some(value("--env")?),
+                "--separator" => separator = Some(value("--separator")?),
+                _ if arg.starts_with('-') => return Err(format!("unknown option `{arg}`")),
+                _ if file.is_some() => return Err(format!("unexpected argument `{arg}`")),
+                _ => file = Some(PathBuf::from(arg)),
+            }
+        }
+
+        let source = match (file, prefix) {
+            (Some(file), None) if separator.is_none() => Source::File(file),
+            (None, Some(prefix)) => Source::Env {
+                prefix,
+                separator: separator.unwrap_or_else(|| "__".to_string()),
+            },
+            (Some(_), _) => return Err("expected either a file or `--env`, not both".to_string()),
+            (None, None) => return Err("expected a file or `--env`".to_string()),
+        };
+
+        Ok(Some(Args {
+            source,
+            budget,
+            paths,
+        }))
+    }
+}
+
+/// Loads the file at `path`, choosing the format by its extension.
+fn load_file(path: &Path) -> Result<Value, String> {
+    let contents = std::fs::read_to_string(path)
+        .map_err(|e| format!("failed to read `{}`: {e}", path.display()))?;
+    let parse_error =
+        |e: &dyn std::fmt::Display| format!("failed to parse `{}`: {e}", path.display());
+
+    match path.extension().and_then(|extension| extension.to_str()) {
+        Some("toml") => toml::from_str(&contents).map_err(|e| parse_error(&e)),
+        Some("json") => serde_json::from_str(&contents).map_err(|e| parse_error(&e)),
+        Some("yaml" | "yml") => serde_yaml::from_str(&contents).map_err(|e| parse_error(&e)),
+        _ => Err(format!(
+            "unsupported format of `{}`, expected `.toml`, `.json`, `.yaml` or `.yml`",
+            path.display()
+        )),
+    }
+}
+
+/// Nests the variables starting with `prefix` by `separator`, lowercasing their keys like `figment` does.
+///
+/// Numbers and booleans are parsed, everything else is kept as a string.
+fn load_env(
+    vars: impl IntoIterator<Item = (String, String)>,
+    prefix: &str,
+    separator: &str,
+) -> Value {
+    let mut root = Map::new();
+
+    for (key, raw) in vars {
+        let Some(key) = key.strip_prefix(prefix) else {
+            continue;
+        };
+
+        let value = match serde_json::from_str(&raw) {
+            Ok(value @ (Value::Number(_) | Value::Bool(_))) => value,
+            _ => Value::String(raw),
+        };
+
+        let mut keys = key.split(separator).map(str::to_lowercase).peekable();
+        let mut table = &mut root;
+        while let Some(key) = keys.next() {
+            if keys.peek().is_none() {
+                table.insert(key, value);
+                break;
+            }
+
+            let entry = table
+                .entry(key)
+                .or_insert_with(|| Value::Object(Map::new()));
+            if !entry.is_object() {
+                *entry = Value::Object(Map::new());
+            }
+            table = entry.as_object_mut().expect("entry is an object");
+        }
+    }
+
+    Value::Object(root)
+}
+
+/// Finds all sections with a `strategy` key, along with their `.`-separated paths.
+fn find_sections(value: &Value) -> Vec<(String, &Value)> {
+    fn walk<'a>(path: String, value: &'a Value, sections: &mut Vec<(String, &'a Value)>) {
+        let join = |key: &str| {
+            if path.is_empty() {
+                key.to_string()
+            } else {
+                format!("{path}.{key}")
+            }
+        };
+
+        match value {
+            Value::Object(object) if object.contains_key("strategy") => {
+                sections.push((
+                    if path.is_empty() {
+                        ".".to_string()
+                    } else {
+                        path
+                    },
+                    value,
+                ));
+            }
+            Value::Object(object) => {
+                for (key, value) in object {
+                    walk(join(key), value, sections);
+                }
+            }
+            Value::Array(array) => {
+                for (index, value) in array.iter().enumerate() {
+                    walk(join(&index.to_string()), value, sections);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    let mut sections = Vec::new();
+    walk(String::new(), value, &mut sections);
+    sections
+}
+
+/// Selects the sections at the given `.`-separated `paths`, whatever their shape, e.g. scalar shorthands.
+///
+/// Array elements are selected by their index, and `.` selects the whole `value`.
+fn select_sections<'a>(
+    value: &'a Value,
+    paths: &[String],
+) -> Result<Vec<(String, &'a Value)>, String> {
+    paths
+        .iter()
+        .map(|path| {
+            let mut keys = path.split('.').filter(|key| !key.is_empty());
+            let section = keys.try_fold(value, |value, key| match value {
+                Value::Object(object) => object.get(key),
+                Value::Array(array) => array.get(key.parse::<usize>().ok()?),
+                _ => None,
+            });
+
+            section
+                .map(|section| (path.clone(), section))
+                .ok_or_else(|| format!("no config found at `{path}`"))
+        })
+        .collect()
+}
+
+/// Result of linting a single section.
+struct Report {
+    output: String,
+    problems: usize,
+}
+
+/// Deserializes, validates and previews the section at `path`.
+fn lint(path: &str, section: &Value, budget: Option<Duration>) -> Report {
+    let mut output = String::new();
+    let mut problems = Vec::new();
+    let mut warnings = Vec::new();
+
+    match CheckedBackoffConfig::deserialize(section) {
+        Err(e) => {
+            let _ = writeln!(output, "{path}: invalid");
+            problems.push(format!("failed to deserialize: {e}"));
+        }
+
+        Ok(CheckedBackoffConfig {
+            config,
+            unknown_keys,
+            deprecations,
+        }) => {
+            let _ = writeln!(output, "{path}: {config}");
+            problems.extend(unknown_keys.iter().map(ToString::to_string));
+            warnings.extend(deprecations.iter().map(ToString::to_string));
+
+            if let Err(e) = config.validate() {
+                problems.push(e.to_string());
+            } else {
+                write_schedule(&mut output, &config);
+
+                let bounds = config.schedule_bounds();
+                if bounds.max_retries == usize::MAX {
+                    warnings.push(
+                        "retries are unlimited, as neither `max_retries` nor a maximum total delay stops them"
+                            .to_string(),
+                    );
+                }
+
+                let worst_case = bounds.total.max;
+                if let Some(budget) = budget
+                    && !config.fits_within(budget)
+                {
+                    problems.push(format!(
+                        "worst case total delay of {} exceeds the budget of {}",
+                        format_duration(worst_case),
+                        format_duration(budget)
+                    ));
+                }
+            }
+        }
+    }
+
+    for warning in &warnings {
+        let _ = writeln!(output, "  warning: {warning}");
+    }
+    for problem in &problems {
+        let _ = writeln!(output, "  problem: {problem}");
+    }
+    let _ = writeln!(output);
+
+    Report {
+        output,
+        problems: problems.len(),
+    }
+}
+
+/// Writes the table of delays and cumulative delays of `config`, followed by the totals.
+fn write_schedule(output: &mut String, config: &BackoffConfig) {
+    let bounds = config.schedule_bounds();
+
+    let _ = writeln!(output, "  attempt  {:<21}  cumulative", "delay");
+
+    let (mut min, mut max) = (Duration::ZERO, Duration::ZERO);
+    for (index, delay) in config.delay_bounds().take(MAX_ROWS).enumerate() {
+        min = min.saturating_add(delay.min);
+        max = max.saturating_add(delay.max).min(bounds.total.max);
+
+        let cumulative = DelayBounds { min, max };
+        let _ = writeln!(
+            output,
+            "  {:>7}  {:<21}  {}",
+            index + 1,
+            format_bounds(delay),
+            format_bounds(cumulative)
+        );
+    }
+
+    if let Some(remaining) = bounds.max_retries.checked_sub(MAX_ROWS)
+        && remaining > 0
+    {
+        let _ = writeln!(output, "  {:>7}  {remaining} more retries", "…");
+    }
+
+    let retries = if bounds.min_retries == bounds.max_retries {
+        bounds.max_retries.to_string()
+    } else {
+        format!("{} - {}", bounds.min_retries, bounds.max_retries)
+    };
+
+    let _ = writeln!(
+        output,
+        "  total: {} over {retries} retries",
+        format_bounds(bounds.total)
+    );
+}
+
+fn format_bounds(bounds: DelayBounds) -> String {
+    let (min, max) = (format_duration(bounds.min), format_duration(bounds.max));
+
+    if min == max {
+        min
+    } else {
+        format!("{min} - {max}")
+    }
+}
+
+/// Formats `duration` rounded to millis, hiding the imprecision of `f32` factors.
+fn format_duration(duration: Duration) -> String {
+    let millis = (duration.as_nanos() + 500_000) / 1_000_000;
+    let rounded = u64::try_from(millis / 1000).map_or(Duration::MAX, |secs| {
+        Duration::new(secs, (millis % 1000) as u32 * 1_000_000)
+    });
+
+    format!("{rounded:?}")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(args: &[&str]) -> Result<Option<Args>, String> {
+        Args::parse(args.iter().map(|arg| arg.to_string()))
+    }
+
+    #[test]
+    fn parses_args() {
+        assert_eq!(
+            args(&["--budget", "30s", "config.toml"]),
+            Ok(Some(Args {
+                source: Source::File(PathBuf::from("config.toml")),
+                budget: Some(Duration::from_secs(30)),
+                paths: Vec::new(),
+            }))
+        );
+        assert_eq!(
+            args(&["--env", "CONFIG__", "--path", "backoff", "--path", "retry"]),
+            Ok(Some(Args {
+                source: Source::Env {
+                    prefix: "CONFIG__".to_string(),
+                    separator: "__".to_string(),
+                },
+                budget: None,
+                paths: vec!["backoff".to_string(), "retry".to_string()],
+            }))
+        );
+        assert_eq!(args(&["--help"]), Ok(None));
+        assert!(args(&[]).is_err());
+        assert!(args(&["config.toml", "--env", "CONFIG__"]).is_err());
+        assert!(args(&["--budget", "soon", "config.toml"]).is_err());
+    }
+
+    #[test]
+    fn loads_env() {
+        let vars = [
+            ("CONFIG__BACKOFF__STRATEGY", "Fibonacci"),
+            ("CONFIG__BACKOFF__MAX_RETRIES", "8"),
+            ("CONFIG__BACKOFF__JITTER_ENABLED", "true"),
+            ("OTHER__BACKOFF__STRATEGY", "Constant"),
+        ]
+        .map(|(key, value)| (key.to_string(), value.to_string()));
+
+        assert_eq!(
+            load_env(vars, "CONFIG__", "__"),
+            serde_json::json!({
+                "backoff": {
+                    "strategy": "Fibonacci",
+                    "max_retries": 8,
+                    "jitter_enabled": true,
+                }
+            })
+        );
+    }
+
+    #[test]
+    fn finds_nested_sections() {
+        let value = serde_json::json!({
+            "backoff": { "strategy": "Constant" },
+            "circuit_breaker": { "open_cooldown": { "strategy": "Exponential" } },
+            "clients": [{ "backoff": { "strategy": "NoBackoff" } }],
+            "name": "strategy",
+        });
+
+        let paths = find_sections(&value)
+            .into_iter()
+            .map(|(path, _)| path)
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            paths,
+            vec![
+                "backoff",
+                "circuit_breaker.open_cooldown",
+                "clients.0.backoff"
+            ]
+        );
+    }
+
+    #[test]
+    fn selects_sections_by_path() {
+        let value = serde_json::json!({
+            "backoff": "500ms",
+            "clients": [{ "backoff": 3 }],
+        });
+        let paths = ["backoff", "clients.0.backoff", "."].map(str::to_string);
+
+        assert_eq!(
+            select_sections(&value, &paths),
+            Ok(vec![
+                ("backoff".to_string(), &value["backoff"]),
+                (
+                    "clients.0.backoff".to_string(),
+                    &value["clients"][0]["backoff"]
+                ),
+                (".".to_string(), &value),
+            ])
+        );
+        assert_eq!(
+            select_sections(&value, &["clients.1.backoff".to_string()]),
+            Err("no config found at `clients.1.backoff`".to_string())
+        );
+    }
+}
@@ -0,0 +1,978 @@
+use crate::*;
+use std::time::Duration;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// Inclusive range of delays.
+pub struct DelayBounds {
+    /// Smallest possible delay.
+    pub min: Duration,
+
+    /// Largest possible delay.
+    pub max: Duration,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// Best and worst case of a [BackoffConfig]'s retry schedule - see [BackoffConfig::schedule_bounds].
+pub struct ScheduleBounds {
+    /// Smallest possible amount of retries.
+    pub min_retries: usize,
+
+    /// Largest possible amount of retries.
+    pub max_retries: usize,
+
+    /// Range of the total delay of all retries.
+    pub total: DelayBounds,
+
+    /// Whether [ScheduleBounds::total] is the smallest range containing every possible total delay.
+    ///
+    /// Otherwise, [ScheduleBounds::total] is still guaranteed to contain every possible total delay, but may be wider -
+    /// see [BackoffConfig::schedule_bounds].
+    pub exact: bool,
+}
+
+/// Amount of retries of a [Backoff::Polynomial] growth phase that are walked one by one before summing the rest of
+/// the phase in closed form.
+const EXACT_RETRIES: usize = 1 << 16;
+
+impl BackoffConfig {
+    /// Returns the range of each retry's delay, for every retry that can happen.
+    ///
+    /// The ranges account for jitter, the maximum delay and the maximum amount of retries. Retries that can only
+    /// happen with some jitter draws (due to the maximum total delay) are included. The ranges are computed lazily, so
+    /// configs with many retries should be limited with [Iterator::take].
+    ///
+    /// [Backoff::Adaptive] bounds assume a fresh [AdaptiveState], as built by [backon::BackoffBuilder::build].
+    pub fn delay_bounds(&self) -> impl Iterator<Item = DelayBounds> {
+        let mut progression = Progression::new(self);
+
+        (0..self.schedule_bounds().max_retries).map(move |_| {
+            let bounds = progression.bounds();
+            progression.step();
+            bounds
+        })
+    }
+
+    /// Computes the best and worst case of this [BackoffConfig]'s retry schedule without sampling.
+    ///
+    /// The total delay range contains the total delay of every possible jitter draw, taking the maximum total delay
+    /// into account. Only the growth phase up to the maximum delay is walked retry by retry, the following retries are
+    /// summed in closed form.
+    ///
+    /// [Backoff::Polynomial] growth phases longer than 65536 retries are estimated in closed form instead, by the
+    /// integrals of the delay polynomial below and above the sum. The total delay range then contains the exact one,
+    /// but may be wider by up to the largest delay of the phase, and [ScheduleBounds::exact] is `false`.
+    pub fn schedule_bounds(&self) -> ScheduleBounds {
+        let runs = self.runs();
+        let retries = runs
+            .iter()
+            .fold(0_usize, |retries, run| retries.saturating_add(run.count));
+        let exact = runs.iter().all(|run| run.exact);
+
+        let Some(max_total_delay) = self.max_total_delay() else {
+            let (min, max) = runs.iter().fold((0_u128, 0_u128), |(min, max), run| {
+                (
+                    min.saturating_add(run.total.0),
+                    max.saturating_add(run.total.1),
+                )
+            });
+
+            return ScheduleBounds {
+                min_retries: retries,
+                max_retries: retries,
+                total: DelayBounds {
+                    min: duration(min),
+                    max: duration(max),
+                },
+                exact,
+            };
+        };
+
+        let cap = nanos(max_total_delay);
+
+        // Sums of the smallest and largest delays of the retries so far.
+        let (mut sum_min, mut sum_max) = (0_u128, 0_u128);
+        // Amount of retries so far.
+        let mut position = 0;
+        // Smallest total delay and smallest amount of retries at which the schedule can stop early.
+        let mut earliest_stop: Option<(u128, usize)> = None;
+
+        // Runs of strategies with a maximum total delay have equal delay ranges, so each retry is `bounds`.
+        for Run { bounds, count, .. } in &runs {
+            let (lo, hi) = (nanos(bounds.min), nanos(bounds.max));
+
+            // Stopping after `position + j` retries is possible once the next delay can exceed the cap. Within a run,
+            // the first such retry has the smallest total delay, but a later run with wider delays may stop with an even
+            // smaller one.
+            if let Some(j) = cap.saturating_sub(sum_max).checked_div(hi) {
+                let stop_min = sum_min.saturating_add(j.saturating_mul(lo));
+                if j < *count as u128 && stop_min <= cap {
+                    let stop_min = stop_min.max(cap.saturating_sub(hi));
+                    earliest_stop = Some(match earliest_stop {
+                        Some((min, min_retries)) => (min.min(stop_min), min_retries),
+                        None => (stop_min, position + j as usize),
+                    });
+                }
+            }
+
+            // Amount of retries of this run whose smallest delays fit into the cap.
+            let fitting = match cap.checked_sub(sum_min) {
+                None => 0,
+                Some(_) if lo == 0 => *count as u128,
+                Some(left) => (left / lo).min(*count as u128),
+            };
+
+            sum_min = sum_min.saturating_add(fitting.saturating_mul(lo));
+            sum_max = sum_max.saturating_add(fitting.saturating_mul(hi));
+            position += fitting as usize;
+
+            if fitting < *count as u128 {
+                break;
+            }
+        }
+
+        let (min, min_retries) = match earliest_stop {
+            Some((min, min_retries)) if min_retries < retries => (min.min(sum_min), min_retries),
+            _ => (sum_min, position),
+        };
+
+        ScheduleBounds {
+            min_retries,
+            max_retries: position,
+            total: DelayBounds {
+                min: duration(min),
+                max: duration(sum_max.min(cap)),
+            },
+            exact,
+        }
+    }
+
+    /// Returns whether the retries of this [BackoffConfig] always finish within `deadline`, i.e. whether the worst
+    /// case total delay does not exceed it.
+    pub fn fits_within(&self, deadline: Duration) -> bool {
+        self.schedule_bounds().total.max <= deadline
+    }
+
+    /// Returns the delay of retry `n` (starting at `0`) without jitter, ignoring the maximum total delay.
+    ///
+    /// Only the growth phase up to the maximum delay is walked, so this takes time independent of `n`.
+    pub(crate) fn base_delay(&self, n: usize) -> Duration {
+        let mut progression = Progression::new(self);
+
+        if let Progression::Polynomial { n: current, .. } = &mut progression {
+            *current = n;
+        } else {
+            for _ in 0..n {
+                if !matches!(progression.step(), Step::Changed) {
+                    break;
+                }
+            }
+        }
+
+        progression.base()
+    }
+
+    /// Returns the maximum total delay of this [BackoffConfig], if any.
+    fn max_total_delay(&self) -> Option<Duration> {
+        match self {
+            BackoffConfig::Exponential(config) => Some(config.max_total_delay),
+            BackoffConfig::RandomizedExponential(config) => Some(config.max_elapsed_time),
+            _ => None,
+        }
+    }
+
+    /// Returns the delay ranges of all retries as runs, ignoring the maximum total delay.
+    fn runs(&self) -> Vec<Run> {
+        let mut progression = Progression::new(self);
+        let mut left = self.max_retries();
+        let mut runs = Vec::new();
+
+        while left > 0 {
+            if let Some(run) = progression.skip_growth(left) {
+                left -= run.count;
+                runs.push(run);
+                continue;
+            }
+
+            let bounds = progression.bounds();
+
+            match progression.step() {
+                Step::Changed => {
+                    runs.push(Run::repeat(bounds, 1));
+                    left -= 1;
+                }
+                Step::Constant => {
+                    runs.push(Run::repeat(bounds, left));
+                    break;
+                }
+                Step::Padded { padding, max_delay } => {
+                    runs.push(Run::padded(bounds, left, padding, max_delay));
+                    break;
+                }
+            }
+        }
+
+        runs
+    }
+}
+
+/// Consecutive retries of a [BackoffConfig], along with the range of their total delay in nanos.
+struct Run {
+    /// Delay range of the first retry of the run, and of every retry of runs built by [Run::repeat].
+    bounds: DelayBounds,
+    count: usize,
+    total: (u128, u128),
+    /// Whether `total` is exact, rather than an estimate containing the exact range - see [Progression::skip_growth].
+    exact: bool,
+}
+
+impl Run {
+    /// Returns a run of `count` retries with equal delay ranges.
+    fn repeat(bounds: DelayBounds, count: usize) -> Run {
+        Run {
+            bounds,
+            count,
+            total: (
+                nanos(bounds.min).saturating_mul(count as u128),
+                nanos(bounds.max).saturating_mul(count as u128),
+            ),
+            exact: true,
+        }
+    }
+
+    /// Returns a run of `count` retries starting at `bounds`, whose largest delay grows by `padding` per retry up to
+    /// `max_delay`.
+    fn padded(bounds: DelayBounds, count: usize, padding: Duration, max_delay: Duration) -> Run {
+        let (max, padding, cap) = (nanos(bounds.max), nanos(padding), nanos(max_delay));
+        let count = count as u128;
+
+        // Retries whose largest delay is still below the cap.
+        let below = cap.saturating_sub(max).div_ceil(padding).min(count);
+        let total_max = max
+            .saturating_mul(below)
+            .saturating_add(padding.saturating_mul(triangle(below.saturating_sub(1))))
+            .saturating_add(cap.saturating_mul(count - below));
+
+        Run {
+            bounds,
+            count: count as usize,
+            total: (nanos(bounds.min).saturating_mul(count), total_max),
+            exact: true,
+        }
+    }
+}
+
+/// How the delay ranges after a [Progression::step] relate to the previous one.
+enum Step {
+    /// The next delay range differs.
+    Changed,
+
+    /// All following delay ranges are equal.
+    Constant,
+
+    /// All following delay ranges are equal, apart from their maximum growing by `padding` per retry up to
+    /// `max_delay`.
+    Padded {
+        padding: Duration,
+        max_delay: Duration,
+    },
+}
+
+/// Progression of the base delays of a [BackoffConfig], mirroring its [Backoff].
+enum Progression {
+    Constant {
+        delay: Duration,
+        jitter: bool,
+    },
+    Exponential {
+        current: Duration,
+        factor: f32,
+        max_delay: Duration,
+        jitter: bool,
+    },
+    Fibonacci {
+        previous: Option<Duration>,
+        current: Duration,
+        min_delay: Duration,
+        max_delay: Duration,
+        jitter: bool,
+    },
+    Adaptive {
+        current: Duration,
+        config: AdaptiveBackoffConfig,
+    },
+    RandomizedExponential {
+        current: Duration,
+        config: RandomizedExponentialBackoffConfig,
+    },
+    Polynomial {
+        n: usize,
+        config: PolynomialBackoffConfig,
+    },
+    Random {
+        config: RandomBackoffConfig,
+    },
+    NoBackoff,
+}
+
+impl Progression {
+    fn new(config: &BackoffConfig) -> Progression {
+        match *config {
+            BackoffConfig::Constant(config) => Progression::Constant {
+                delay: config.delay,
+                jitter: config.jitter.is_enabled(),
+            },
+            BackoffConfig::Exponential(config) => Progression::Exponential {
+                current: config.initial_delay,
+                factor: config.factor,
+                max_delay: config.max_delay,
+                jitter: config.jitter.is_enabled(),
+            },
+            BackoffConfig::Fibonacci(config) => Progression::Fibonacci {
+                previous: None,
+                current: config.initial_delay,
+                min_delay: config.initial_delay,
+                max_delay: config.max_delay,
+                jitter: config.jitter.is_enabled(),
+            },
+            BackoffConfig::Adaptive(config) => Progression::Adaptive {
+                current: AdaptiveState::new(config).base_delay(),
+                config,
+            },
+            BackoffConfig::RandomizedExponential(config) => Progression::RandomizedExponential {
+                current: config.initial_interval,
+                config,
+            },
+            BackoffConfig::Polynomial(config) => Progression::Polynomial { n: 0, config },
+            BackoffConfig::Random(config) => Progression::Random { config },
+            BackoffConfig::NoBackoff => Progression::NoBackoff,
+        }
+    }
+
+    /// Returns the delay of the current retry without jitter.
+    fn base(&self) -> Duration {
+        match *self {
+            Progression::Constant { delay, .. } => delay,
+            Progression::Exponential { current, .. }
+            | Progression::Fibonacci { current, .. }
+            | Progression::Adaptive { current, .. }
+            | Progression::RandomizedExponential { current, .. } => current,
+            Progression::Polynomial { n, config } => crate::backoff::polynomial_delay(&config, n),
+            Progression::Random { config } => config.min_delay,
+            Progression::NoBackoff => Duration::ZERO,
+        }
+    }
+
+    /// Returns the delay range of the current retry.
+    fn bounds(&self) -> DelayBounds {
+        let jittered = |delay: Duration, jitter: Option<Duration>| DelayBounds {
+            min: delay,
+            max: jitter.map_or(delay, |jitter| {
+                delay.saturating_add(crate::backoff::max_jitter(jitter))
+            }),
+        };
+
+        match *self {
+            Progression::Constant { delay, jitter } => jittered(delay, jitter.then_some(delay)),
+            Progression::Exponential {
+                current, jitter, ..
+            } => jittered(current, jitter.then_some(current)),
+            Progression::Fibonacci {
+                current,
+                min_delay,
+                jitter,
+                ..
+            } => jittered(current, jitter.then_some(min_delay)),
+            Progression::Adaptive { current, config } => {
+                jittered(current, config.jitter.is_enabled().then_some(current))
+            }
+            Progression::RandomizedExponential { current, config } => {
+                let factor = f64::from(config.randomization_factor);
+                if !config.jitter.is_enabled() || factor <= 0.0 {
+                    return jittered(current, None);
+                }
+
+                DelayBounds {
+                    min: crate::backoff::scale(current, 1.0 - factor),
+                    max: crate::backoff::scale(current, 1.0 + factor),
+                }
+            }
+            Progression::Polynomial { n, config } => {
+                let bounds = jittered(
+                    crate::backoff::polynomial_delay(&config, n),
+                    config
+                        .jitter
+                        .is_enabled()
+                        .then(|| crate::backoff::polynomial_padding(&config, n)),
+                );
+
+                DelayBounds {
+                    min: bounds.min,
+                    max: bounds.max.min(config.max_delay),
+                }
+            }
+            Progression::Random { config } => jittered(
+                config.min_delay,
+                Some(config.max_delay.saturating_sub(config.min_delay)),
+            ),
+            Progression::NoBackoff => jittered(Duration::ZERO, None),
+        }
+    }
+
+    /// Moves to the next retry, returning how the following retries relate to the current one.
+    fn step(&mut self) -> Step {
+        match self {
+            Progression::Constant { .. } | Progression::Random { .. } | Progression::NoBackoff => {
+                Step::Constant
+            }
+
+            Progression::Exponential {
+                current,
+                factor,
+                max_delay,
+                ..
+            } => {
+                let mut next = *current;
+                if next < *max_delay {
+                    next = Duration::try_from_secs_f32(*factor * next.as_secs_f32())
+                        .unwrap_or(Duration::MAX);
+                }
+                if next > *max_delay {
+                    next = *max_delay;
+                }
+
+                let changed = next != *current;
+                *current = next;
+                Step::changed(changed)
+            }
+
+            Progression::Fibonacci {
+                previous,
+                current,
+                max_delay,
+                ..
+            } => {
+                if *current >= *max_delay {
+                    return Step::Constant;
+                }
+
+                let next = match previous {
+                    Some(previous) => current.saturating_add(*previous),
+                    None => *current,
+                };
+                let changed = next != *current || previous.is_none();
+                *previous = Some(*current);
+                *current = next;
+                Step::changed(changed)
+            }
+
+            Progression::Adaptive { current, config } => {
+                let next = Duration::try_from_secs_f64(
+                    current.as_secs_f64() * f64::from(config.increase_factor),
+                )
+                .unwrap_or(Duration::MAX)
+                .clamp(config.min_delay, config.max_delay.max(config.min_delay));
+
+                let changed = next != *current;
+                *current = next;
+                Step::changed(changed)
+            }
+
+            Progression::RandomizedExponential { current, config } => {
+                let next = crate::backoff::scale(*current, f64::from(config.multiplier))
+                    .min(config.max_interval);
+
+                let changed = next != *current;
+                *current = next;
+                Step::changed(changed)
+            }
+
+            Progression::Polynomial { n, config } => {
+                // Delays never decrease, so once a delay equals the limit of all delays, the following ones do too.
+                let growing = crate::backoff::polynomial_delay(config, *n)
+                    != crate::backoff::polynomial_delay(config, usize::MAX);
+                let padded = config.jitter.is_enabled()
+                    && !config.padding.is_zero()
+                    && crate::backoff::polynomial_delay(config, *n).saturating_add(
+                        crate::backoff::max_jitter(crate::backoff::polynomial_padding(config, *n)),
+                    ) < config.max_delay;
+                *n += 1;
+
+                if growing {
+                    Step::Changed
+                } else if padded {
+                    Step::Padded {
+                        padding: config.padding,
+                        max_delay: config.max_delay,
+                    }
+                } else {
+                    Step::Constant
+                }
+            }
+        }
+    }
+
+    /// Estimates the sum of up to `left` retries of a long [Backoff::Polynomial] growth phase in closed form, moving
+    /// past them.
+    ///
+    /// As delays never decrease, the sum of the delays of retries `n..k` is within the integrals of the delay
+    /// polynomial over `n - 1..k - 1` and `n..k`, which differ by the largest delay of the phase at most. The returned
+    /// run is therefore not exact.
+    fn skip_growth(&mut self, left: usize) -> Option<Run> {
+        let Progression::Polynomial { n: start, config } = *self else {
+            return None;
+        };
+
+        let delay = |n: usize| crate::backoff::polynomial_delay(&config, n);
+        let limit = delay(usize::MAX);
+        if start < EXACT_RETRIES || delay(start) == limit {
+            return None;
+        }
+
+        // First retry of the phase that reaches the limit, or the end of the retries.
+        let (mut low, mut high) = (start, start.saturating_add(left));
+        while low < high {
+            let middle = low + (high - low) / 2;
+            if delay(middle) == limit {
+                high = middle;
+            } else {
+                low = middle + 1;
+            }
+        }
+        let count = low - start;
+
+        let bounds = self.bounds();
+        let exponent = f64::from(config.exponent) + 1.0;
+        let integral = |from: usize, to: usize| {
+            nanos(config.scale) as f64 * ((to as f64).powf(exponent) - (from as f64).powf(exponent))
+                / exponent
+        };
+
+        // Delays are rounded to whole nanos, so every retry may be off by one.
+        let base = nanos(config.base).saturating_mul(count as u128);
+        let min = base
+            .saturating_add(integral(start - 1, low - 1) as u128)
+            .saturating_sub(count as u128);
+        let mut max = base
+            .saturating_add(integral(start, low).ceil() as u128)
+            .saturating_add(count as u128);
+        if config.jitter.is_enabled() {
+            let paddings = triangle(low as u128) - triangle(start as u128);
+            max = max.saturating_add(nanos(config.padding).saturating_mul(paddings));
+        }
+        let max = max.min(nanos(config.max_delay).saturating_mul(count as u128));
+
+        *self = Progression::Polynomial { n: low, config };
+
+        Some(Run {
+            bounds,
+            count,
+            total: (min, max),
+            exact: false,
+        })
+    }
+}
+
+impl Step {
+    fn changed(changed: bool) -> Step {
+        if changed {
+            Step::Changed
+        } else {
+            Step::Constant
+        }
+    }
+}
+
+fn nanos(duration: Duration) -> u128 {
+    duration.as_nanos()
+}
+
+/// Returns `0 + 1 + ... + n`.
+fn triangle(n: u128) -> u128 {
+    if n.is_multiple_of(2) {
+        (n / 2).saturating_mul(n + 1)
+    } else {
+        n.saturating_mul(n.div_ceil(2))
+    }
+}
+
+/// Converts `nanos` to a [Duration], saturating at [Duration::MAX].
+fn duration(nanos: u128) -> Duration {
+    match u64::try_from(nanos / 1_000_000_000) {
+        Ok(secs) => Duration::new(secs, (nanos % 1_000_000_000) as u32),
+        Err(_) => Duration::MAX,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Rounds to millis, as `f32` arithmetic makes delays slightly off.
+    fn millis(bounds: DelayBounds) -> (u128, u128) {
+        let round = |delay: Duration| (delay.as_nanos() + 500_000) / 1_000_000;
+        (round(bounds.min), round(bounds.max))
+    }
+
+    fn exponential(jitter: Jitter, max_total_delay: Duration) -> BackoffConfig {
+        BackoffConfig::Exponential(ExponentialBackoffConfig {
+            initial_delay: Duration::from_millis(100),
+            factor: 2_f32,
+            max_delay: Duration::from_millis(800),
+            max_retries: 5,
+            max_total_delay,
+            jitter,
+        })
+    }
+
+    #[test]
+    fn constant_bounds() {
+        let config = BackoffConfig::Constant(ConstantBackoffConfig {
+            delay: Duration::from_secs(1),
+            max_retries: 3,
+            jitter: Jitter::Random,
+        });
+
+        assert_eq!(
+            config.delay_bounds().map(millis).collect::<Vec<_>>(),
+            vec![(1000, 2000); 3]
+        );
+        let bounds = config.schedule_bounds();
+        assert_eq!((bounds.min_retries, bounds.max_retries), (3, 3));
+        assert_eq!(millis(bounds.total), (3000, 6000));
+    }
+
+    #[test]
+    fn constant_bounds_with_many_retries() {
+        let config = BackoffConfig::Constant(ConstantBackoffConfig {
+            delay: Duration::from_secs(1),
+            max_retries: usize::MAX,
+            jitter: Jitter::Disabled,
+        });
+
+        assert_eq!(
+            config.schedule_bounds().total.min,
+            Duration::from_secs(usize::MAX as u64)
+        );
+    }
+
+    #[test]
+    fn exponential_bounds_without_jitter() {
+        let config = exponential(Jitter::Disabled, Duration::from_millis(1500 + 1));
+
+        assert_eq!(
+            config.delay_bounds().map(millis).collect::<Vec<_>>(),
+            vec![(100, 100), (200, 200), (400, 400), (800, 800)]
+        );
+        let bounds = config.schedule_bounds();
+        assert_eq!((bounds.min_retries, bounds.max_retries), (4, 4));
+        assert_eq!(millis(bounds.total), (1500, 1500));
+    }
+
+    #[test]
+    fn exponential_bounds_with_jitter() {
+        let config = exponential(Jitter::Random, Duration::from_secs(1000));
+
+        assert_eq!(
+            config.delay_bounds().map(millis).collect::<Vec<_>>(),
+            vec![(100, 200), (200, 400), (400, 800), (800, 1600), (800, 1600)]
+        );
+        assert_eq!(millis(config.schedule_bounds().total), (2300, 4600));
+    }
+
+    #[test]
+    fn exponential_bounds_with_jitter_and_max_total_delay() {
+        let config = exponential(Jitter::Random, Duration::from_millis(1000));
+
+        let bounds = config.schedule_bounds();
+        assert_eq!((bounds.min_retries, bounds.max_retries), (2, 3));
+        assert_eq!(millis(bounds.total), (300, 1000));
+        assert_eq!(bounds.total.max, Duration::from_millis(1000));
+        assert_eq!(config.delay_bounds().count(), 3);
+    }
+
+    #[test]
+    fn fibonacci_bounds() {
+        let config = BackoffConfig::Fibonacci(FibonacciBackoffConfig {
+            initial_delay: Duration::from_millis(100),
+            max_delay: Duration::from_millis(800),
+            max_retries: 8,
+            jitter: Jitter::Random,
+        });
+
+        assert_eq!(
+            config.delay_bounds().map(millis).collect::<Vec<_>>(),
+            vec![
+                (100, 200),
+                (100, 200),
+                (200, 300),
+                (300, 400),
+                (500, 600),
+                (800, 900),
+                (800, 900),
+                (800, 900)
+            ]
+        );
+    }
+
+    #[test]
+    fn adaptive_bounds() {
+        let config = BackoffConfig::Adaptive(AdaptiveBackoffConfig {
+            initial_delay: Duration::from_millis(100),
+            min_delay: Duration::from_millis(50),
+            max_delay: Duration::from_millis(800),
+            increase_factor: 2_f32,
+            decrease_step: Duration::from_millis(50),
+            max_retries: 6,
+            jitter: Jitter::Disabled,
+        });
+
+        assert_eq!(
+            config.delay_bounds().map(millis).collect::<Vec<_>>(),
+            vec![
+                (100, 100),
+                (200, 200),
+                (400, 400),
+                (800, 800),
+                (800, 800),
+                (800, 800)
+            ]
+        );
+    }
+
+    #[test]
+    fn randomized_exponential_bounds() {
+        let config = BackoffConfig::RandomizedExponential(RandomizedExponentialBackoffConfig {
+            initial_interval: Duration::from_millis(100),
+            randomization_factor: 0.5,
+            multiplier: 2_f32,
+            max_interval: Duration::from_millis(400),
+            max_elapsed_time: Duration::from_millis(1500),
+            max_retries: 10,
+            jitter: Jitter::Random,
+        });
+
+        assert_eq!(
+            config.delay_bounds().map(millis).collect::<Vec<_>>(),
+            vec![
+                (50, 150),
+                (100, 300),
+                (200, 600),
+                (200, 600),
+                (200, 600),
+                (200, 600),
+                (200, 600),
+                (200, 600)
+            ]
+        );
+        assert_eq!(
+            config.schedule_bounds(),
+            ScheduleBounds {
+                min_retries: 3,
+                max_retries: 8,
+                total: DelayBounds {
+                    min: Duration::from_millis(900),
+                    max: Duration::from_millis(1500),
+                },
+                exact: true,
+            }
+        );
+    }
+
+    #[test]
+    fn polynomial_bounds() {
+        let config = BackoffConfig::Polynomial(PolynomialBackoffConfig {
+            base: Duration::from_millis(100),
+            exponent: 2_f32,
+            scale: Duration::from_millis(100),
+            padding: Duration::from_millis(10),
+            max_delay: Duration::from_millis(1000),
+            max_retries: 5,
+            jitter: Jitter::Random,
+        });
+
+        assert_eq!(
+            config.delay_bounds().map(millis).collect::<Vec<_>>(),
+            vec![
+                (100, 110),
+                (200, 220),
+                (500, 530),
+                (1000, 1000),
+                (1000, 1000)
+            ]
+        );
+    }
+
+    #[test]
+    fn polynomial_bounds_with_constant_base() {
+        let config = BackoffConfig::Polynomial(PolynomialBackoffConfig {
+            base: Duration::from_millis(100),
+            exponent: 1_f32,
+            scale: Duration::ZERO,
+            padding: Duration::from_millis(10),
+            max_delay: Duration::from_millis(130),
+            max_retries: 5,
+            jitter: Jitter::Random,
+        });
+
+        assert_eq!(
+            config.delay_bounds().map(millis).collect::<Vec<_>>(),
+            vec![(100, 110), (100, 120), (100, 130), (100, 130), (100, 130)]
+        );
+        assert_eq!(millis(config.schedule_bounds().total), (500, 620));
+    }
+
+    #[test]
+    fn polynomial_bounds_of_long_growth_phase() {
+        let linear = PolynomialBackoffConfig::new()
+            .base(Duration::ZERO)
+            .exponent(1_f32)
+            .scale(Duration::from_millis(1))
+            .max_delay(Duration::MAX)
+            .jitter(Jitter::Disabled);
+        let config = BackoffConfig::Polynomial(linear.max_retries(100_000));
+
+        // 0ms + 1ms + ... + 99_999ms
+        let exact = Duration::from_millis(99_999 * 100_000 / 2);
+
+        let bounds = config.schedule_bounds();
+        assert_eq!((bounds.min_retries, bounds.max_retries), (100_000, 100_000));
+        assert!(bounds.total.min <= exact);
+        assert!(exact <= bounds.total.max);
+        assert!(bounds.total.max - bounds.total.min <= Duration::from_secs(100));
+        assert!(!bounds.exact);
+
+        let bounds = BackoffConfig::Polynomial(linear.max_retries(EXACT_RETRIES)).schedule_bounds();
+        assert!(bounds.exact);
+        assert_eq!(bounds.total.min, bounds.total.max);
+    }
+
+    #[test]
+    fn bounds_with_unlimited_retries() {
+        let config = BackoffConfig::Polynomial(
+            PolynomialBackoffConfig::new()
+                .max_retries(usize::MAX)
+                .jitter(Jitter::Random),
+        );
+
+        let bounds = config.schedule_bounds();
+        assert_eq!(
+            (bounds.min_retries, bounds.max_retries),
+            (usize::MAX, usize::MAX)
+        );
+        assert_eq!(bounds.total.max, Duration::MAX);
+        assert!(!config.fits_within(Duration::from_secs(3600)));
+        assert_eq!(config.delay_bounds().take(3).count(), 3);
+
+        let configs = [
+            BackoffConfig::Constant(ConstantBackoffConfig::new().max_retries(usize::MAX)),
+            BackoffConfig::Exponential(
+                ExponentialBackoffConfig::new()
+                    .max_retries(usize::MAX)
+                    .max_total_delay(Duration::MAX),
+            ),
+            BackoffConfig::Fibonacci(FibonacciBackoffConfig::new().max_retries(usize::MAX)),
+            BackoffConfig::Adaptive(AdaptiveBackoffConfig {
+                max_retries: usize::MAX,
+                ..Default::default()
+            }),
+            BackoffConfig::RandomizedExponential(
+                RandomizedExponentialBackoffConfig::new().max_elapsed_time(Duration::MAX),
+            ),
+            BackoffConfig::Polynomial(
+                PolynomialBackoffConfig::new()
+                    .exponent(1_f32)
+                    .max_retries(usize::MAX),
+            ),
+            BackoffConfig::Random(RandomBackoffConfig::new().max_retries(usize::MAX)),
+        ];
+
+        for config in configs {
+            let bounds = config.schedule_bounds();
+            assert!(bounds.max_retries > 1_000_000_000, "{config:?}");
+            assert!(!config.fits_within(Duration::from_secs(3600)), "{config:?}");
+        }
+    }
+
+    #[test]
+    fn random_bounds() {
+        let config = BackoffConfig::Random(RandomBackoffConfig {
+            min_delay: Duration::from_millis(100),
+            max_delay: Duration::from_millis(300),
+            max_retries: 3,
+            seed: None,
+        });
+
+        assert_eq!(
+            config.delay_bounds().map(millis).collect::<Vec<_>>(),
+            vec![(100, 300), (100, 300), (100, 300)]
+        );
+        assert_eq!(millis(config.schedule_bounds().total), (300, 900));
+    }
+
+    #[test]
+    fn no_backoff_bounds() {
+        assert_eq!(BackoffConfig::NoBackoff.delay_bounds().count(), 0);
+        assert_eq!(
+            BackoffConfig::NoBackoff.schedule_bounds(),
+            ScheduleBounds {
+                min_retries: 0,
+                max_retries: 0,
+                total: DelayBounds {
+                    min: Duration::ZERO,
+                    max: Duration::ZERO,
+                },
+                exact: true,
+            }
+        );
+    }
+
+    #[test]
+    fn bounds_contain_simulated_schedules() {
+        let configs = [
+            exponential(Jitter::Random, Duration::from_millis(1000)),
+            exponential(Jitter::Random, Duration::from_millis(2500)),
+            BackoffConfig::Fibonacci(FibonacciBackoffConfig {
+                max_retries: 10,
+                ..Default::default()
+            }),
+            BackoffConfig::Adaptive(AdaptiveBackoffConfig::default()),
+            BackoffConfig::RandomizedExponential(RandomizedExponentialBackoffConfig {
+                max_elapsed_time: Duration::from_secs(20),
+                ..Default::default()
+            }),
+            BackoffConfig::Polynomial(PolynomialBackoffConfig::default()),
+            BackoffConfig::Random(RandomBackoffConfig::default()),
+        ];
+
+        for config in configs {
+            let bounds = config.schedule_bounds();
+            let delay_bounds = config.delay_bounds().collect::<Vec<_>>();
+
+            for seed in 0..1000 {
+                let schedule = config.simulate(seed);
+
+                assert!(
+                    (bounds.min_retries..=bounds.max_retries).contains(&schedule.attempts.len())
+                );
+                assert!(bounds.total.min <= schedule.total_delay());
+                assert!(schedule.total_delay() <= bounds.total.max);
+
+                for (attempt, bounds) in schedule.attempts.iter().zip(&delay_bounds) {
+                    assert!((bounds.min..=bounds.max).contains(&attempt.delay));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn fits_within() {
+        let config = exponential(Jitter::Random, Duration::from_secs(1000));
+
+        let worst_case = config.schedule_bounds().total.max;
+
+        assert!(config.fits_within(worst_case));
+        assert!(config.fits_within(Duration::from_secs(5)));
+        assert!(!config.fits_within(worst_case - Duration::from_nanos(1)));
+        assert!(!config.fits_within(Duration::from_secs(4)));
+    }
+}
@@ -0,0 +1,487 @@
+use crate::*;
+use backon::BackoffBuilder;
+use serde::Deserialize;
+use std::collections::VecDeque;
+use std::fmt::{Display, Formatter};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+#[derive(Debug, smart_default::SmartDefault, Clone, Copy, Deserialize, PartialEq)]
+/// Configuration for [CircuitBreaker].
+///
+/// The breaker opens once the failure rate over the last [CircuitBreakerConfig::window_size] calls reaches
+/// [CircuitBreakerConfig::failure_rate_threshold]. While open, calls are rejected for a cooldown taken from
+/// [CircuitBreakerConfig::open_cooldown], so repeated opens back off. After the cooldown the breaker lets
+/// [CircuitBreakerConfig::half_open_probes] calls through: if they all succeed it closes, otherwise it opens again.
+pub struct CircuitBreakerConfig {
+    /// Failure rate in `(0.0, 1.0]` at which the breaker opens.
+    ///
+    /// Defaults to `0.5` - see [defaults::failure_rate_threshold].
+    #[serde(default = "defaults::failure_rate_threshold")]
+    #[default(defaults::failure_rate_threshold())]
+    pub failure_rate_threshold: f32,
+
+    /// Amount of most recent calls the failure rate is computed over.
+    ///
+    /// The breaker does not open before this many calls were recorded.
+    ///
+    /// Defaults to `100` - see [defaults::window_size].
+    #[serde(default = "defaults::window_size")]
+    #[default(defaults::window_size())]
+    pub window_size: usize,
+
+    /// Schedule of cooldowns for consecutive opens. Once exhausted, the last cooldown is reused.
+    ///
+    /// Defaults to an exponential backoff - see [defaults::open_cooldown].
+    #[serde(default = "defaults::open_cooldown")]
+    #[default(defaults::open_cooldown())]
+    pub open_cooldown: BackoffConfig,
+
+    /// Amount of successful probe calls required in the half-open state to close the breaker.
+    ///
+    /// Defaults to `5` - see [defaults::half_open_probes].
+    #[serde(default = "defaults::half_open_probes")]
+    #[default(defaults::half_open_probes())]
+    pub half_open_probes: usize,
+}
+
+impl CircuitBreakerConfig {
+    /// Checks that this [CircuitBreakerConfig] describes a sensible breaker.
+    pub fn validate(&self) -> Result<(), ValidationError> {
+        if !(self.failure_rate_threshold > 0.0 && self.failure_rate_threshold <= 1.0) {
+            return Err(ValidationError::new(
+                "failure_rate_threshold",
+                format!(
+                    "must be within (0.0, 1.0], got {}",
+                    self.failure_rate_threshold
+                ),
+            ));
+        }
+
+        if self.window_size == 0 {
+            return Err(ValidationError::new(
+                "window_size",
+                "must be greater than zero",
+            ));
+        }
+
+        if self.half_open_probes == 0 {
+            return Err(ValidationError::new(
+                "half_open_probes",
+                "must be greater than zero",
+            ));
+        }
+
+        self.open_cooldown.validate()
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// State of a [CircuitBreaker].
+pub enum CircuitState {
+    /// Calls are permitted and their outcomes are recorded.
+    Closed,
+
+    /// Calls are rejected until the cooldown elapses.
+    Open,
+
+    /// A limited amount of probe calls is permitted to test whether the dependency recovered.
+    HalfOpen,
+}
+
+#[derive(Debug, Clone)]
+/// Thread-safe circuit breaker - see [CircuitBreakerConfig].
+///
+/// Cloning the breaker is cheap and all clones share the same state. Callers ask for permission via
+/// [CircuitBreaker::try_acquire] and report outcomes of permitted calls via [CircuitBreaker::record_success] and
+/// [CircuitBreaker::record_failure], or use [CircuitBreaker::call] that does both.
+pub struct CircuitBreaker {
+    inner: Arc<Inner>,
+}
+
+#[derive(Debug)]
+struct Inner {
+    config: CircuitBreakerConfig,
+    clock: Box<dyn Clock>,
+    state: Mutex<State>,
+}
+
+#[derive(Debug)]
+struct State {
+    phase: Phase,
+    cooldown: Backoff,
+    last_cooldown: Duration,
+}
+
+#[derive(Debug)]
+enum Phase {
+    Closed { outcomes: VecDeque<bool> },
+    Open { since: Instant, cooldown: Duration },
+    HalfOpen { permitted: usize, succeeded: usize },
+}
+
+impl CircuitBreaker {
+    /// Creates a new, closed [CircuitBreaker] using the [SystemClock].
+    pub fn new(config: CircuitBreakerConfig) -> CircuitBreaker {
+        CircuitBreaker::with_clock(config, SystemClock)
+    }
+
+    /// Creates a new, closed [CircuitBreaker] using the given [Clock].
+    pub fn with_clock(config: CircuitBreakerConfig, clock: impl Clock + 'static) -> CircuitBreaker {
+        CircuitBreaker {
+            inner: Arc::new(Inner {
+                config,
+                clock: Box::new(clock),
+                state: Mutex::new(State {
+                    phase: Phase::Closed {
+                        outcomes: VecDeque::new(),
+                    },
+                    cooldown: config.open_cooldown.build(),
+                    last_cooldown: Duration::ZERO,
+                }),
+            }),
+        }
+    }
+
+    /// Returns the [CircuitBreakerConfig] of this breaker.
+    pub fn config(&self) -> CircuitBreakerConfig {
+        self.inner.config
+    }
+
+    /// Returns the current [CircuitState], moving from [CircuitState::Open] to [CircuitState::HalfOpen] once the
+    /// cooldown elapsed.
+    pub fn state(&self) -> CircuitState {
+        let mut state = self.inner.lock();
+        self.inner.expire_cooldown(&mut state);
+
+        match state.phase {
+            Phase::Closed { .. } => CircuitState::Closed,
+            Phase::Open { .. } => CircuitState::Open,
+            Phase::HalfOpen { .. } => CircuitState::HalfOpen,
+        }
+    }
+
+    /// Returns whether a call is permitted. Every permitted call must report its outcome.
+    pub fn try_acquire(&self) -> bool {
+        let mut state = self.inner.lock();
+        self.inner.expire_cooldown(&mut state);
+
+        match &mut state.phase {
+            Phase::Closed { .. } => true,
+            Phase::Open { .. } => false,
+            Phase::HalfOpen { permitted, .. } => {
+                if *permitted < self.inner.config.half_open_probes {
+                    *permitted += 1;
+                    true
+                } else {
+                    false
+                }
+            }
+        }
+    }
+
+    /// Records a successful call.
+    pub fn record_success(&self) {
+        self.inner.record(true);
+    }
+
+    /// Records a failed call.
+    pub fn record_failure(&self) {
+        self.inner.record(false);
+    }
+
+    /// Runs `f` if permitted, recording its outcome.
+    pub fn call<T, E>(
+        &self,
+        f: impl FnOnce() -> Result<T, E>,
+    ) -> Result<T, CircuitBreakerError<E>> {
+        if !self.try_acquire() {
+            return Err(CircuitBreakerError::Open);
+        }
+
+        match f() {
+            Ok(value) => {
+                self.record_success();
+                Ok(value)
+            }
+            Err(error) => {
+                self.record_failure();
+                Err(CircuitBreakerError::Failed(error))
+            }
+        }
+    }
+}
+
+impl Inner {
+    fn lock(&self) -> std::sync::MutexGuard<'_, State> {
+        self.state.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    fn expire_cooldown(&self, state: &mut State) {
+        if let Phase::Open { since, cooldown } = state.phase
+            && self.clock.now().saturating_duration_since(since) >= cooldown
+        {
+            state.phase = Phase::HalfOpen {
+                permitted: 0,
+                succeeded: 0,
+            };
+        }
+    }
+
+    fn record(&self, success: bool) {
+        let mut state = self.lock();
+        self.expire_cooldown(&mut state);
+
+        match &mut state.phase {
+            Phase::Closed { outcomes } => {
+                outcomes.push_back(success);
+                if outcomes.len() > self.config.window_size {
+                    outcomes.pop_front();
+                }
+
+                let failures = outcomes.iter().filter(|success| !**success).count();
+                if outcomes.len() >= self.config.window_size
+                    && failures as f32 >= self.config.failure_rate_threshold * outcomes.len() as f32
+                {
+                    self.open(&mut state);
+                }
+            }
+
+            Phase::Open { .. } => {}
+
+            Phase::HalfOpen { succeeded, .. } => {
+                if !success {
+                    self.open(&mut state);
+                } else {
+                    *succeeded += 1;
+                    if *succeeded >= self.config.half_open_probes {
+                        state.phase = Phase::Closed {
+                            outcomes: VecDeque::new(),
+                        };
+                        state.cooldown = self.config.open_cooldown.build();
+                        state.last_cooldown = Duration::ZERO;
+                    }
+                }
+            }
+        }
+    }
+
+    fn open(&self, state: &mut State) {
+        let cooldown = state.cooldown.next().unwrap_or(state.last_cooldown);
+        state.last_cooldown = cooldown;
+        state.phase = Phase::Open {
+            since: self.clock.now(),
+            cooldown,
+        };
+    }
+}
+
+#[derive(Debug, PartialEq)]
+/// Error returned by [CircuitBreaker::call].
+pub enum CircuitBreakerError<E> {
+    /// The call was rejected because the breaker is open.
+    Open,
+
+    /// The call was permitted but failed.
+    Failed(E),
+}
+
+impl<E: Display> Display for CircuitBreakerError<E> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CircuitBreakerError::Open => write!(f, "circuit breaker is open"),
+            CircuitBreakerError::Failed(e) => write!(f, "{e}"),
+        }
+    }
+}
+
+impl<E: std::error::Error + 'static> std::error::Error for CircuitBreakerError<E> {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            CircuitBreakerError::Open => None,
+            CircuitBreakerError::Failed(e) => Some(e),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn breaker(clock: &ManualClock) -> CircuitBreaker {
+        CircuitBreaker::with_clock(
+            CircuitBreakerConfig {
+                failure_rate_threshold: 0.5,
+                window_size: 4,
+                open_cooldown: BackoffConfig::Exponential(ExponentialBackoffConfig {
+                    initial_delay: Duration::from_secs(1),
+                    factor: 2_f32,
+                    max_delay: Duration::from_secs(60),
+                    max_retries: 2,
+                    max_total_delay: Duration::from_secs(60),
+                    jitter: Jitter::Disabled,
+                }),
+                half_open_probes: 2,
+            },
+            clock.clone(),
+        )
+    }
+
+    fn open(breaker: &CircuitBreaker) {
+        while breaker.state() != CircuitState::Open {
+            assert!(breaker.try_acquire());
+            breaker.record_failure();
+        }
+    }
+
+    #[test]
+    fn defaults() {
+        assert_eq!(
+            CircuitBreakerConfig::default(),
+            CircuitBreakerConfig {
+                failure_rate_threshold: defaults::failure_rate_threshold(),
+                window_size: defaults::window_size(),
+                open_cooldown: defaults::open_cooldown(),
+                half_open_probes: defaults::half_open_probes(),
+            }
+        );
+        assert_eq!(CircuitBreakerConfig::default().validate(), Ok(()));
+    }
+
+    #[test]
+    fn validate() {
+        let zero_threshold = CircuitBreakerConfig {
+            failure_rate_threshold: 0.0,
+            ..Default::default()
+        };
+        let empty_window = CircuitBreakerConfig {
+            window_size: 0,
+            ..Default::default()
+        };
+        let no_probes = CircuitBreakerConfig {
+            half_open_probes: 0,
+            ..Default::default()
+        };
+        let invalid_cooldown = CircuitBreakerConfig {
+            open_cooldown: BackoffConfig::Exponential(ExponentialBackoffConfig {
+                factor: 0.5,
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            zero_threshold.validate().unwrap_err().field,
+            "failure_rate_threshold"
+        );
+        assert_eq!(empty_window.validate().unwrap_err().field, "window_size");
+        assert_eq!(no_probes.validate().unwrap_err().field, "half_open_probes");
+        assert_eq!(invalid_cooldown.validate().unwrap_err().field, "factor");
+    }
+
+    #[test]
+    fn opens_when_failure_rate_reaches_threshold() {
+        let clock = ManualClock::new();
+        let breaker = breaker(&clock);
+
+        breaker.record_failure();
+        breaker.record_failure();
+        breaker.record_success();
+        assert_eq!(breaker.state(), CircuitState::Closed);
+
+        breaker.record_success();
+        assert_eq!(breaker.state(), CircuitState::Open);
+        assert!(!breaker.try_acquire());
+    }
+
+    #[test]
+    fn stays_closed_below_threshold_over_sliding_window() {
+        let clock = ManualClock::new();
+        let breaker = breaker(&clock);
+
+        for _ in 0..10 {
+            breaker.record_failure();
+            breaker.record_success();
+            breaker.record_success();
+            breaker.record_success();
+        }
+
+        assert_eq!(breaker.state(), CircuitState::Closed);
+    }
+
+    #[test]
+    fn half_open_probes_close_breaker() {
+        let clock = ManualClock::new();
+        let breaker = breaker(&clock);
+        open(&breaker);
+
+        clock.advance(Duration::from_millis(999));
+        assert_eq!(breaker.state(), CircuitState::Open);
+
+        clock.advance(Duration::from_millis(1));
+        assert_eq!(breaker.state(), CircuitState::HalfOpen);
+
+        assert!(breaker.try_acquire());
+        assert!(breaker.try_acquire());
+        assert!(!breaker.try_acquire());
+
+        breaker.record_success();
+        assert_eq!(breaker.state(), CircuitState::HalfOpen);
+        breaker.record_success();
+        assert_eq!(breaker.state(), CircuitState::Closed);
+    }
+
+    #[test]
+    fn repeated_opens_back_off() {
+        let clock = ManualClock::new();
+        let breaker = breaker(&clock);
+
+        let assert_cooldown = |cooldown: Duration| {
+            clock.advance(cooldown - Duration::from_millis(1));
+            assert_eq!(breaker.state(), CircuitState::Open);
+            clock.advance(Duration::from_millis(1));
+            assert_eq!(breaker.state(), CircuitState::HalfOpen);
+        };
+
+        open(&breaker);
+        assert_cooldown(Duration::from_secs(1));
+
+        for _ in 0..2 {
+            assert!(breaker.try_acquire());
+            breaker.record_failure();
+            assert_cooldown(Duration::from_secs(2));
+        }
+
+        for _ in 0..2 {
+            assert!(breaker.try_acquire());
+            breaker.record_success();
+        }
+        assert_eq!(breaker.state(), CircuitState::Closed);
+
+        open(&breaker);
+        assert_cooldown(Duration::from_secs(1));
+    }
+
+    #[test]
+    fn call_records_outcomes() {
+        let clock = ManualClock::new();
+        let breaker = breaker(&clock);
+
+        assert_eq!(breaker.call(|| Ok::<_, &str>(1)), Ok(1));
+        for _ in 0..3 {
+            assert_eq!(
+                breaker.call(|| Err::<(), _>("boom")),
+                Err(CircuitBreakerError::Failed("boom"))
+            );
+        }
+
+        assert_eq!(
+            breaker.call(|| Ok::<_, &str>(1)),
+            Err(CircuitBreakerError::Open)
+        );
+        assert_eq!(
+            CircuitBreakerError::<&str>::Open.to_string(),
+            "circuit breaker is open"
+        );
+    }
+}
@@ -0,0 +1,77 @@
+use std::fmt::Debug;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+/// Source of the current time for the stateful policies of this crate, e.g. [crate::RetryBudget].
+pub trait Clock: Debug + Send + Sync {
+    /// Returns the current [Instant].
+    fn now(&self) -> Instant;
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+/// [Clock] backed by [Instant::now].
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> Instant {
+        Instant::now()
+    }
+}
+
+#[derive(Debug, Clone)]
+/// [Clock] that only moves when advanced manually. Useful in tests.
+///
+/// Cloning the clock is cheap and all clones share the same time.
+pub struct ManualClock {
+    now: Arc<Mutex<Instant>>,
+}
+
+impl ManualClock {
+    /// Creates a new [ManualClock] starting at [Instant::now].
+    pub fn new() -> ManualClock {
+        ManualClock {
+            now: Arc::new(Mutex::new(Instant::now())),
+        }
+    }
+
+    /// Moves the clock forward by the given [Duration].
+    pub fn advance(&self, duration: Duration) {
+        *self.lock() += duration;
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, Instant> {
+        self.now
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+impl Default for ManualClock {
+    fn default() -> ManualClock {
+        ManualClock::new()
+    }
+}
+
+impl Clock for ManualClock {
+    fn now(&self) -> Instant {
+        *self.lock()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn manual_clock_moves_only_when_advanced() {
+        let clock = ManualClock::new();
+        let clone = clock.clone();
+        let start = clock.now();
+
+        assert_eq!(clock.now(), start);
+
+        clone.advance(Duration::from_secs(5));
+
+        assert_eq!(clock.now(), start + Duration::from_secs(5));
+    }
+}
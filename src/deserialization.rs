@@ -0,0 +1,980 @@
+use crate::duration::{DurationUnit, bare_number, format_duration};
+use crate::*;
+use serde::de::value::{MapDeserializer, SeqDeserializer};
+use serde::de::{Error, IntoDeserializer, MapAccess, SeqAccess, Unexpected, Visitor};
+use serde::{Deserialize, Deserializer};
+use std::cell::Cell;
+use std::cmp::Reverse;
+use std::fmt::{Display, Formatter};
+use std::marker::PhantomData;
+
+/// Canonical names of the strategies, as used in the `strategy` key and serialized.
+pub(crate) const STRATEGIES: &[&str] = &[
+    "Constant",
+    "Exponential",
+    "Fibonacci",
+    "Adaptive",
+    "RandomizedExponential",
+    "Polynomial",
+    "Random",
+    "NoBackoff",
+];
+
+/// Alternative names of the strategies, mapped to their canonical names.
+pub(crate) const STRATEGY_ALIASES: &[(&str, &str)] = &[
+    ("exp", "Exponential"),
+    ("fib", "Fibonacci"),
+    ("fixed", "Constant"),
+    ("none", "NoBackoff"),
+    ("off", "NoBackoff"),
+    ("disabled", "NoBackoff"),
+];
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+/// Key of a [BackoffConfig] that is not valid for its `strategy` - see [BackoffConfig::deserialize_strict].
+pub struct UnknownKey {
+    /// The unknown key.
+    pub key: String,
+
+    /// The `strategy` of the [BackoffConfig].
+    pub strategy: &'static str,
+
+    /// Valid key of the `strategy` that is closest to the unknown key, if any is close enough.
+    pub suggestion: Option<&'static str>,
+}
+
+impl Display for UnknownKey {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "unknown key `{}` for strategy `{}`",
+            self.key, self.strategy
+        )?;
+
+        match self.suggestion {
+            Some(suggestion) => write!(f, ", did you mean `{suggestion}`?"),
+            None => Ok(()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+/// [BackoffConfig] deserialized leniently, along with the [UnknownKey]s that were ignored.
+///
+/// Plain [BackoffConfig] deserialization ignores unknown keys silently, while
+/// [BackoffConfig::deserialize_strict] rejects them. This type collects them, e.g. to log them as warnings.
+pub struct CheckedBackoffConfig {
+    /// The deserialized [BackoffConfig].
+    pub config: BackoffConfig,
+
+    /// Keys that are not valid for the `strategy` of [CheckedBackoffConfig::config], in order of appearance.
+    pub unknown_keys: Vec<UnknownKey>,
+
+    /// Keys of older schema versions that were migrated, e.g. to log them as warnings at startup - see
+    /// [crate::CURRENT_SCHEMA_VERSION].
+    pub deprecations: Vec<Deprecation>,
+}
+
+impl<'de> Deserialize<'de> for CheckedBackoffConfig {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let content = Content::deserialize(deserializer)?;
+        from_content(content)
+    }
+}
+
+impl<'de> Deserialize<'de> for BackoffConfig {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        CheckedBackoffConfig::deserialize(deserializer).map(|checked| checked.config)
+    }
+}
+
+impl BackoffConfig {
+    /// Deserializes a [BackoffConfig], rejecting keys that are not valid for its `strategy`.
+    ///
+    /// The error suggests the closest valid key for every unknown key. Use it via
+    /// `#[serde(deserialize_with = "BackoffConfig::deserialize_strict")]`.
+    pub fn deserialize_strict<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<BackoffConfig, D::Error> {
+        let checked = CheckedBackoffConfig::deserialize(deserializer)?;
+
+        if checked.unknown_keys.is_empty() {
+            Ok(checked.config)
+        } else {
+            let unknown_keys = checked
+                .unknown_keys
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>();
+            Err(D::Error::custom(unknown_keys.join("; ")))
+        }
+    }
+}
+
+/// Deserializes a [BackoffConfig] from buffered `content`, collecting its unknown keys.
+fn from_content<E: Error>(content: Content) -> Result<CheckedBackoffConfig, E> {
+    let mut entries = match content {
+        Content::Map(entries) => entries,
+        Content::Some(content) => return from_content(*content),
+        scalar => {
+            return from_shorthand(scalar).map(|config| CheckedBackoffConfig {
+                config,
+                unknown_keys: Vec::new(),
+                deprecations: Vec::new(),
+            });
+        }
+    };
+
+    let position = entries
+        .iter()
+        .position(|(key, _)| key.as_str() == Some("strategy"))
+        .ok_or_else(|| E::missing_field("strategy"))?;
+    let (_, strategy) = entries.remove(position);
+
+    let strategy = match strategy {
+        Content::String(strategy) => resolve_strategy(&strategy)?,
+        other => {
+            return Err(E::invalid_type(other.unexpected(), &"a strategy name"));
+        }
+    };
+
+    let fields = strategy_fields(strategy);
+    let deprecations = crate::migration::migrate(&mut entries, fields)?;
+    apply_duration_unit(&mut entries, strategy)?;
+
+    let mut unknown_keys = Vec::new();
+    entries.retain(|(key, _)| match key.as_str() {
+        Some(key) if fields.contains(&key) => true,
+        key => {
+            let key = key.map_or_else(|| format!("{key:?}"), str::to_string);
+            unknown_keys.push(UnknownKey {
+                suggestion: suggest(&key, fields),
+                key,
+                strategy,
+            });
+            false
+        }
+    });
+
+    Ok(CheckedBackoffConfig {
+        config: variant(strategy, entries)?,
+        unknown_keys,
+        deprecations,
+    })
+}
+
+/// Removes the `duration_unit` key from `entries` and converts the bare numbers of the duration fields of `strategy`
+/// from that unit, e.g. `delay = 2` to 2 seconds with `duration_unit = "seconds"`.
+///
+/// Without the key, bare numbers are milliseconds - see [deserialize_duration].
+fn apply_duration_unit<E: Error>(
+    entries: &mut Vec<(Content, Content)>,
+    strategy: &str,
+) -> Result<(), E> {
+    let Some(position) = entries
+        .iter()
+        .position(|(key, _)| key.as_str() == Some("duration_unit"))
+    else {
+        return Ok(());
+    };
+    let (_, unit) = entries.remove(position);
+    let unit = DurationUnit::deserialize(ContentDeserializer::<E>::new(unit))?;
+
+    let fields = duration_fields(strategy);
+    for (key, value) in entries.iter_mut() {
+        let Some(key) = key.as_str().filter(|key| fields.contains(key)) else {
+            continue;
+        };
+        let amount = match value {
+            Content::U64(amount) => *amount as f64,
+            Content::I64(amount) => *amount as f64,
+            Content::F64(amount) => *amount,
+            Content::String(amount) => match bare_number(amount) {
+                Some(amount) => amount,
+                None => continue,
+            },
+            _ => continue,
+        };
+
+        let duration = unit
+            .duration(amount)
+            .map_err(|e| E::custom(format_args!("invalid `{key}`: {e}")))?;
+        *value = Content::String(format_duration(duration));
+    }
+
+    Ok(())
+}
+
+/// Returns the config of the canonical `strategy` with default values.
+pub(crate) fn default_config(strategy: &str) -> BackoffConfig {
+    variant::<serde::de::value::Error>(strategy, Vec::new())
+        .expect("every strategy has defaults for all fields")
+}
+
+/// Deserializes the config of the canonical `strategy` from its valid `entries`.
+fn variant<E: Error>(strategy: &str, entries: Vec<(Content, Content)>) -> Result<BackoffConfig, E> {
+    let content = ContentDeserializer::<E>::new(Content::Map(entries));
+
+    Ok(match strategy {
+        "Constant" => BackoffConfig::Constant(ConstantBackoffConfig::deserialize(content)?),
+        "Exponential" => {
+            BackoffConfig::Exponential(ExponentialBackoffConfig::deserialize(content)?)
+        }
+        "Fibonacci" => BackoffConfig::Fibonacci(FibonacciBackoffConfig::deserialize(content)?),
+        "Adaptive" => BackoffConfig::Adaptive(AdaptiveBackoffConfig::deserialize(content)?),
+        "RandomizedExponential" => BackoffConfig::RandomizedExponential(
+            RandomizedExponentialBackoffConfig::deserialize(content)?,
+        ),
+        "Polynomial" => BackoffConfig::Polynomial(PolynomialBackoffConfig::deserialize(content)?),
+        "Random" => {
+            let config = BackoffConfig::Random(RandomBackoffConfig::deserialize(content)?);
+            config.validate().map_err(E::custom)?;
+            config
+        }
+        _ => BackoffConfig::NoBackoff,
+    })
+}
+
+/// Deserializes a scalar shorthand of a [BackoffConfig]:
+///
+/// - a strategy name or alias, e.g. `"none"`, for the strategy with default values;
+/// - a delay with a unit, e.g. `"500ms"`, for [BackoffConfig::Constant] with that delay;
+/// - an amount of retries, e.g. `3` or `"3"`, for [BackoffConfig::Constant] with that amount of retries.
+///
+/// Bare numbers are always amounts of retries here, unlike in duration fields, and other bare numbers like `"1.5"` are
+/// rejected.
+fn from_shorthand<E: Error>(content: Content) -> Result<BackoffConfig, E> {
+    let expected = "a backoff config with a `strategy` key, a strategy name (e.g. `none`), a delay with a unit \
+                    (e.g. `500ms`) or an amount of retries (e.g. `3`)";
+
+    let max_retries = match content {
+        Content::U64(retries) => usize::try_from(retries).ok(),
+        Content::I64(retries) => usize::try_from(retries).ok(),
+        Content::String(ref s) => s.trim().parse::<usize>().ok(),
+        _ => None,
+    };
+    if let Some(max_retries) = max_retries {
+        return Ok(BackoffConfig::Constant(ConstantBackoffConfig {
+            max_retries,
+            ..Default::default()
+        }));
+    }
+
+    let Content::String(s) = content else {
+        return Err(E::invalid_type(content.unexpected(), &expected));
+    };
+
+    if let Ok(strategy) = resolve_strategy::<E>(&s) {
+        return variant(strategy, Vec::new());
+    }
+
+    match parse_duration(&s) {
+        Ok(delay) => Ok(BackoffConfig::Constant(ConstantBackoffConfig {
+            delay,
+            ..Default::default()
+        })),
+        Err(_) => Err(E::invalid_value(Unexpected::Str(&s), &expected)),
+    }
+}
+
+/// Returns the canonical name of `strategy`, or an error suggesting the closest one.
+///
+/// Names are case-insensitive and may be one of the [STRATEGY_ALIASES].
+fn resolve_strategy<E: Error>(strategy: &str) -> Result<&'static str, E> {
+    let canonical = STRATEGIES.iter().map(|name| (*name, *name));
+    if let Some((_, strategy)) = canonical
+        .chain(STRATEGY_ALIASES.iter().copied())
+        .find(|(name, _)| name.eq_ignore_ascii_case(strategy))
+    {
+        return Ok(strategy);
+    }
+
+    let quoted = |names: &mut dyn Iterator<Item = &str>| {
+        names
+            .map(|name| format!("`{name}`"))
+            .collect::<Vec<_>>()
+            .join(", ")
+    };
+    let names = STRATEGIES
+        .iter()
+        .copied()
+        .chain(STRATEGY_ALIASES.iter().map(|(alias, _)| *alias))
+        .collect::<Vec<_>>();
+    let suggestion = suggest(strategy, &names)
+        .map(|suggestion| format!(", did you mean `{suggestion}`?"))
+        .unwrap_or_default();
+
+    Err(E::custom(format!(
+        "unknown strategy `{strategy}`, expected one of {} or aliases {} (case-insensitive){suggestion}",
+        quoted(&mut STRATEGIES.iter().copied()),
+        quoted(&mut STRATEGY_ALIASES.iter().map(|(alias, _)| *alias)),
+    )))
+}
+
+/// Returns the keys that are valid for `strategy`.
+pub(crate) fn strategy_fields(strategy: &str) -> &'static [&'static str] {
+    match strategy {
+        "Constant" => fields::<ConstantBackoffConfig>(),
+        "Exponential" => fields::<ExponentialBackoffConfig>(),
+        "Fibonacci" => fields::<FibonacciBackoffConfig>(),
+        "Adaptive" => fields::<AdaptiveBackoffConfig>(),
+        "RandomizedExponential" => fields::<RandomizedExponentialBackoffConfig>(),
+        "Polynomial" => fields::<PolynomialBackoffConfig>(),
+        "Random" => fields::<RandomBackoffConfig>(),
+        _ => &[],
+    }
+}
+
+/// Returns the keys of `strategy` that hold durations.
+fn duration_fields(strategy: &str) -> &'static [&'static str] {
+    match strategy {
+        "Constant" => &["delay"],
+        "Exponential" => &["initial_delay", "max_delay", "max_total_delay"],
+        "Fibonacci" => &["initial_delay", "max_delay"],
+        "Adaptive" => &["initial_delay", "min_delay", "max_delay", "decrease_step"],
+        "RandomizedExponential" => &["initial_interval", "max_interval", "max_elapsed_time"],
+        "Polynomial" => &["base", "scale", "padding", "max_delay"],
+        "Random" => &["min_delay", "max_delay"],
+        _ => &[],
+    }
+}
+
+/// Returns the field names of the struct `T`, as declared to serde.
+fn fields<T: for<'de> Deserialize<'de>>() -> &'static [&'static str] {
+    /// [Deserializer] that only records the fields `T` asks for.
+    struct Probe<'a>(&'a Cell<&'static [&'static str]>);
+
+    impl<'de> Deserializer<'de> for Probe<'_> {
+        type Error = serde::de::value::Error;
+
+        fn deserialize_any<V: Visitor<'de>>(self, _: V) -> Result<V::Value, Self::Error> {
+            Err(Error::custom("expected a struct"))
+        }
+
+        fn deserialize_struct<V: Visitor<'de>>(
+            self,
+            _: &'static str,
+            fields: &'static [&'static str],
+            _: V,
+        ) -> Result<V::Value, Self::Error> {
+            self.0.set(fields);
+            Err(Error::custom("fields recorded"))
+        }
+
+        serde::forward_to_deserialize_any! {
+            bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string bytes byte_buf option unit
+            unit_struct newtype_struct seq tuple tuple_struct map enum identifier ignored_any
+        }
+    }
+
+    let fields = Cell::new(&[][..]);
+    let _ = T::deserialize(Probe(&fields));
+    fields.get()
+}
+
+/// Returns the candidate closest to `input`, if it is close enough to be a likely typo.
+///
+/// Ties are broken in favour of the candidate sharing the longest prefix with `input`.
+pub(crate) fn suggest(input: &str, candidates: &[&'static str]) -> Option<&'static str> {
+    let input = input.to_lowercase();
+    let threshold = 2.max(input.chars().count() / 3);
+
+    candidates
+        .iter()
+        .map(|candidate| {
+            let lowercase = candidate.to_lowercase();
+            let prefix = input
+                .chars()
+                .zip(lowercase.chars())
+                .take_while(|(a, b)| a == b)
+                .count();
+            (distance(&input, &lowercase), Reverse(prefix), *candidate)
+        })
+        .filter(|(distance, _, _)| *distance <= threshold)
+        .min()
+        .map(|(_, _, candidate)| candidate)
+}
+
+/// Levenshtein distance between `a` and `b`.
+fn distance(a: &str, b: &str) -> usize {
+    let b = b.chars().collect::<Vec<_>>();
+    let mut previous = (0..=b.len()).collect::<Vec<_>>();
+
+    for (i, a) in a.chars().enumerate() {
+        let mut current = vec![i + 1];
+        for (j, b) in b.iter().enumerate() {
+            let substitution = previous[j] + usize::from(a != *b);
+            current.push(substitution.min(previous[j + 1] + 1).min(current[j] + 1));
+        }
+        previous = current;
+    }
+
+    previous[b.len()]
+}
+
+#[derive(Debug, Clone, PartialEq)]
+/// Buffered self-describing value, so a [BackoffConfig] can be inspected before it is deserialized.
+pub(crate) enum Content {
+    Bool(bool),
+    U64(u64),
+    I64(i64),
+    F64(f64),
+    String(String),
+    Bytes(Vec<u8>),
+    Unit,
+    Some(Box<Content>),
+    Seq(Vec<Content>),
+    Map(Vec<(Content, Content)>),
+}
+
+impl Content {
+    pub(crate) fn as_str(&self) -> Option<&str> {
+        match self {
+            Content::String(s) => Some(s),
+            _ => None,
+        }
+    }
+
+    pub(crate) fn unexpected(&self) -> Unexpected<'_> {
+        match self {
+            Content::Bool(b) => Unexpected::Bool(*b),
+            Content::U64(n) => Unexpected::Unsigned(*n),
+            Content::I64(n) => Unexpected::Signed(*n),
+            Content::F64(n) => Unexpected::Float(*n),
+            Content::String(s) => Unexpected::Str(s),
+            Content::Bytes(b) => Unexpected::Bytes(b),
+            Content::Unit => Unexpected::Unit,
+            Content::Some(_) => Unexpected::Option,
+            Content::Seq(_) => Unexpected::Seq,
+            Content::Map(_) => Unexpected::Map,
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Content {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct ContentVisitor;
+
+        impl<'de> Visitor<'de> for ContentVisitor {
+            type Value = Content;
+
+            fn expecting(&self, f: &mut Formatter) -> std::fmt::Result {
+                f.write_str("any value")
+            }
+
+            fn visit_bool<E: Error>(self, v: bool) -> Result<Content, E> {
+                Ok(Content::Bool(v))
+            }
+
+            fn visit_i64<E: Error>(self, v: i64) -> Result<Content, E> {
+                Ok(Content::I64(v))
+            }
+
+            fn visit_i128<E: Error>(self, v: i128) -> Result<Content, E> {
+                i64::try_from(v)
+                    .map(Content::I64)
+                    .map_err(|_| E::custom(format!("integer `{v}` is out of range")))
+            }
+
+            fn visit_u64<E: Error>(self, v: u64) -> Result<Content, E> {
+                Ok(Content::U64(v))
+            }
+
+            fn visit_u128<E: Error>(self, v: u128) -> Result<Content, E> {
+                u64::try_from(v)
+                    .map(Content::U64)
+                    .map_err(|_| E::custom(format!("integer `{v}` is out of range")))
+            }
+
+            fn visit_f64<E: Error>(self, v: f64) -> Result<Content, E> {
+                Ok(Content::F64(v))
+            }
+
+            fn visit_str<E: Error>(self, v: &str) -> Result<Content, E> {
+                Ok(Content::String(v.to_string()))
+            }
+
+            fn visit_string<E: Error>(self, v: String) -> Result<Content, E> {
+                Ok(Content::String(v))
+            }
+
+            fn visit_bytes<E: Error>(self, v: &[u8]) -> Result<Content, E> {
+                Ok(Content::Bytes(v.to_vec()))
+            }
+
+            fn visit_none<E: Error>(self) -> Result<Content, E> {
+                Ok(Content::Unit)
+            }
+
+            fn visit_unit<E: Error>(self) -> Result<Content, E> {
+                Ok(Content::Unit)
+            }
+
+            fn visit_some<D: Deserializer<'de>>(
+                self,
+                deserializer: D,
+            ) -> Result<Content, D::Error> {
+                Content::deserialize(deserializer).map(|content| Content::Some(Box::new(content)))
+            }
+
+            fn visit_newtype_struct<D: Deserializer<'de>>(
+                self,
+                deserializer: D,
+            ) -> Result<Content, D::Error> {
+                Content::deserialize(deserializer)
+            }
+
+            fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Content, A::Error> {
+                let mut elements = Vec::new();
+                while let Some(element) = seq.next_element()? {
+                    elements.push(element);
+                }
+                Ok(Content::Seq(elements))
+            }
+
+            fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Content, A::Error> {
+                let mut entries = Vec::new();
+                while let Some(entry) = map.next_entry()? {
+                    entries.push(entry);
+                }
+                Ok(Content::Map(entries))
+            }
+        }
+
+        deserializer.deserialize_any(ContentVisitor)
+    }
+}
+
+/// [Deserializer] of buffered [Content].
+pub(crate) struct ContentDeserializer<E> {
+    content: Content,
+    error: PhantomData<E>,
+}
+
+impl<E> ContentDeserializer<E> {
+    pub(crate) fn new(content: Content) -> ContentDeserializer<E> {
+        ContentDeserializer {
+            content,
+            error: PhantomData,
+        }
+    }
+}
+
+impl<'de, E: Error> IntoDeserializer<'de, E> for Content {
+    type Deserializer = ContentDeserializer<E>;
+
+    fn into_deserializer(self) -> ContentDeserializer<E> {
+        ContentDeserializer::new(self)
+    }
+}
+
+impl<'de, E: Error> Deserializer<'de> for ContentDeserializer<E> {
+    type Error = E;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, E> {
+        match self.content {
+            Content::Bool(v) => visitor.visit_bool(v),
+            Content::U64(v) => visitor.visit_u64(v),
+            Content::I64(v) => visitor.visit_i64(v),
+            Content::F64(v) => visitor.visit_f64(v),
+            Content::String(v) => visitor.visit_string(v),
+            Content::Bytes(v) => visitor.visit_byte_buf(v),
+            Content::Unit => visitor.visit_unit(),
+            Content::Some(v) => visitor.visit_some(ContentDeserializer::new(*v)),
+            Content::Seq(v) => {
+                let mut seq = SeqDeserializer::new(v.into_iter());
+                let value = visitor.visit_seq(&mut seq)?;
+                seq.end()?;
+                Ok(value)
+            }
+            Content::Map(v) => {
+                let mut map = MapDeserializer::new(v.into_iter());
+                let value = visitor.visit_map(&mut map)?;
+                map.end()?;
+                Ok(value)
+            }
+        }
+    }
+
+    fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, E> {
+        match self.content {
+            Content::Unit => visitor.visit_none(),
+            Content::Some(v) => visitor.visit_some(ContentDeserializer::new(*v)),
+            _ => visitor.visit_some(self),
+        }
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(
+        self,
+        _: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, E> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_enum<V: Visitor<'de>>(
+        self,
+        name: &'static str,
+        variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, E> {
+        match self.content {
+            Content::String(variant) => visitor.visit_enum(variant.into_deserializer()),
+            content => ContentDeserializer::new(content)
+                .deserialize_any(visitor)
+                .map_err(|e: E| {
+                    E::custom(format!(
+                        "invalid `{name}`, expected one of {variants:?}: {e}"
+                    ))
+                }),
+        }
+    }
+
+    serde::forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string bytes byte_buf unit unit_struct seq
+        tuple tuple_struct map struct identifier ignored_any
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde::de::value::Error as ValueError;
+    use std::time::Duration;
+
+    fn map(entries: &[(&str, Content)]) -> Content {
+        Content::Map(
+            entries
+                .iter()
+                .map(|(key, value)| (Content::String(key.to_string()), value.clone()))
+                .collect(),
+        )
+    }
+
+    fn string(s: &str) -> Content {
+        Content::String(s.to_string())
+    }
+
+    fn checked(content: Content) -> Result<CheckedBackoffConfig, ValueError> {
+        CheckedBackoffConfig::deserialize(ContentDeserializer::<ValueError>::new(content))
+    }
+
+    fn strict(content: Content) -> Result<BackoffConfig, ValueError> {
+        BackoffConfig::deserialize_strict(ContentDeserializer::<ValueError>::new(content))
+    }
+
+    #[test]
+    fn records_struct_fields() {
+        assert_eq!(
+            fields::<FibonacciBackoffConfig>(),
+            &["initial_delay", "max_delay", "max_retries", "jitter"]
+        );
+    }
+
+    #[test]
+    fn suggests_close_candidates() {
+        let fields = fields::<ExponentialBackoffConfig>();
+
+        assert_eq!(suggest("max_retry", fields), Some("max_retries"));
+        assert_eq!(suggest("inital_delay", fields), Some("initial_delay"));
+        assert_eq!(suggest("MAX_DELAY", fields), Some("max_delay"));
+        assert_eq!(suggest("timeout", fields), None);
+        assert_eq!(suggest("Exponental", STRATEGIES), Some("Exponential"));
+    }
+
+    #[test]
+    fn collects_unknown_keys() {
+        let checked = checked(map(&[
+            ("strategy", string("Exponential")),
+            ("inital_delay", string("1s")),
+            ("max_retries", Content::U64(3)),
+            ("timeout", string("1s")),
+        ]))
+        .unwrap();
+
+        assert_eq!(
+            checked.config,
+            BackoffConfig::Exponential(ExponentialBackoffConfig {
+                max_retries: 3,
+                ..Default::default()
+            })
+        );
+        assert_eq!(
+            checked.unknown_keys,
+            vec![
+                UnknownKey {
+                    key: "inital_delay".to_string(),
+                    strategy: "Exponential",
+                    suggestion: Some("initial_delay"),
+                },
+                UnknownKey {
+                    key: "timeout".to_string(),
+                    strategy: "Exponential",
+                    suggestion: None,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn strict_rejects_unknown_keys() {
+        let error = strict(map(&[
+            ("strategy", string("Constant")),
+            ("max_retry", Content::U64(10)),
+            ("factor", Content::F64(2.0)),
+        ]))
+        .unwrap_err();
+
+        assert_eq!(
+            error.to_string(),
+            "unknown key `max_retry` for strategy `Constant`, did you mean `max_retries`?; \
+             unknown key `factor` for strategy `Constant`"
+        );
+
+        assert_eq!(
+            strict(map(&[
+                ("strategy", string("Constant")),
+                ("delay", string("1s")),
+            ])),
+            Ok(BackoffConfig::Constant(ConstantBackoffConfig {
+                delay: Duration::from_secs(1),
+                ..Default::default()
+            }))
+        );
+    }
+
+    #[test]
+    fn suggests_strategy() {
+        let error = checked(map(&[("strategy", string("Fibonaci"))])).unwrap_err();
+
+        assert_eq!(
+            error.to_string(),
+            "unknown strategy `Fibonaci`, expected one of `Constant`, `Exponential`, `Fibonacci`, `Adaptive`, \
+             `RandomizedExponential`, `Polynomial`, `Random`, \
+             `NoBackoff` or aliases `exp`, `fib`, `fixed`, `none`, `off`, `disabled` (case-insensitive), \
+             did you mean `Fibonacci`?"
+        );
+    }
+
+    #[test]
+    fn resolves_aliases_case_insensitively() {
+        for (name, expected) in [
+            ("exponential", "Exponential"),
+            ("EXP", "Exponential"),
+            ("Fib", "Fibonacci"),
+            ("fixed", "Constant"),
+            ("constant", "Constant"),
+            ("adaptive", "Adaptive"),
+            ("nobackoff", "NoBackoff"),
+            ("None", "NoBackoff"),
+            ("off", "NoBackoff"),
+            ("DISABLED", "NoBackoff"),
+        ] {
+            assert_eq!(resolve_strategy::<ValueError>(name), Ok(expected), "{name}");
+        }
+
+        assert_eq!(
+            checked(map(&[
+                ("strategy", string("exp")),
+                ("max_retries", Content::U64(2))
+            ]))
+            .unwrap()
+            .config,
+            BackoffConfig::Exponential(ExponentialBackoffConfig {
+                max_retries: 2,
+                ..Default::default()
+            })
+        );
+    }
+
+    #[test]
+    fn serializes_canonical_strategy() {
+        let config = checked(map(&[
+            ("strategy", string("fixed")),
+            ("delay", string("1500ms")),
+        ]))
+        .unwrap()
+        .config;
+
+        let json = serde_json::to_value(config).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!({
+                "strategy": "Constant",
+                "delay": "1s 500ms",
+                "max_retries": 4,
+                "jitter": true,
+            })
+        );
+        assert_eq!(
+            serde_json::from_value::<BackoffConfig>(json).unwrap(),
+            config
+        );
+
+        assert_eq!(
+            serde_json::to_value(BackoffConfig::NoBackoff).unwrap(),
+            serde_json::json!({ "strategy": "NoBackoff" })
+        );
+    }
+
+    #[test]
+    fn requires_strategy() {
+        assert_eq!(
+            checked(map(&[("delay", string("1s"))]))
+                .unwrap_err()
+                .to_string(),
+            "missing field `strategy`"
+        );
+        assert!(checked(Content::Bool(true)).is_err());
+        assert!(checked(Content::Seq(Vec::new())).is_err());
+    }
+
+    #[test]
+    fn deserializes_shorthands() {
+        let shorthand = |content| checked(content).map(|checked| checked.config);
+
+        assert_eq!(shorthand(string("none")), Ok(BackoffConfig::NoBackoff));
+        assert_eq!(shorthand(string("Off")), Ok(BackoffConfig::NoBackoff));
+        assert_eq!(
+            shorthand(string("exponential")),
+            Ok(BackoffConfig::Exponential(Default::default()))
+        );
+        assert_eq!(
+            shorthand(string("500ms")),
+            Ok(BackoffConfig::Constant(ConstantBackoffConfig {
+                delay: Duration::from_millis(500),
+                ..Default::default()
+            }))
+        );
+        assert_eq!(
+            shorthand(string("PT2S")),
+            Ok(BackoffConfig::Constant(ConstantBackoffConfig {
+                delay: Duration::from_secs(2),
+                ..Default::default()
+            }))
+        );
+
+        let three_retries = Ok(BackoffConfig::Constant(ConstantBackoffConfig {
+            max_retries: 3,
+            ..Default::default()
+        }));
+        assert_eq!(shorthand(Content::U64(3)), three_retries);
+        assert_eq!(shorthand(Content::I64(3)), three_retries);
+        assert_eq!(shorthand(string("3")), three_retries);
+        assert_eq!(
+            shorthand(string("500")),
+            Ok(BackoffConfig::Constant(ConstantBackoffConfig {
+                max_retries: 500,
+                ..Default::default()
+            }))
+        );
+        assert_eq!(
+            shorthand(Content::Some(Box::new(Content::U64(3)))),
+            three_retries
+        );
+
+        assert!(shorthand(Content::I64(-1)).is_err());
+        assert!(shorthand(string("1.5")).is_err());
+        assert_eq!(
+            shorthand(string("sometimes")).unwrap_err().to_string(),
+            "invalid value: string \"sometimes\", expected a backoff config with a `strategy` key, a strategy name \
+             (e.g. `none`), a delay with a unit (e.g. `500ms`) or an amount of retries (e.g. `3`)"
+        );
+    }
+
+    #[test]
+    fn lists_duration_fields() {
+        for strategy in STRATEGIES {
+            for field in strategy_fields(strategy) {
+                let result =
+                    variant::<ValueError>(strategy, vec![(string(field), string("500ms"))]);
+                assert_eq!(
+                    result.is_ok(),
+                    duration_fields(strategy).contains(field),
+                    "{strategy}.{field}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn applies_duration_unit() {
+        let constant = |unit: &str, delay: Content| {
+            checked(map(&[
+                ("strategy", string("Constant")),
+                ("duration_unit", string(unit)),
+                ("delay", delay),
+                ("max_retries", Content::U64(2)),
+            ]))
+            .map(|checked| (checked.config, checked.unknown_keys))
+        };
+        let delay = |delay| {
+            Ok((
+                BackoffConfig::Constant(ConstantBackoffConfig {
+                    delay,
+                    max_retries: 2,
+                    ..Default::default()
+                }),
+                Vec::new(),
+            ))
+        };
+
+        assert_eq!(
+            constant("seconds", Content::U64(2)),
+            delay(Duration::from_secs(2))
+        );
+        assert_eq!(
+            constant("seconds", string("1.5")),
+            delay(Duration::from_millis(1500))
+        );
+        assert_eq!(
+            constant("seconds", string("500ms")),
+            delay(Duration::from_millis(500))
+        );
+        assert_eq!(
+            constant("milliseconds", string("500")),
+            delay(Duration::from_millis(500))
+        );
+        assert_eq!(
+            checked(map(&[
+                ("strategy", string("Constant")),
+                ("delay", string("500"))
+            ]))
+            .unwrap()
+            .config,
+            BackoffConfig::Constant(ConstantBackoffConfig {
+                delay: Duration::from_millis(500),
+                ..Default::default()
+            })
+        );
+
+        assert_eq!(
+            constant("seconds", Content::I64(-1))
+                .unwrap_err()
+                .to_string(),
+            "invalid `delay`: invalid duration `-1`: must be a non-negative, finite and not too large number of \
+             seconds; expected a duration string (e.g. `500ms`, `1m 30s`) or an ISO 8601 duration (e.g. `PT0.5S`)"
+        );
+        assert_eq!(
+            constant("minutes", Content::U64(2))
+                .unwrap_err()
+                .to_string(),
+            "unknown variant `minutes`, expected `milliseconds` or `seconds`"
+        );
+    }
+
+    #[test]
+    fn no_backoff_has_no_keys() {
+        let checked = checked(map(&[
+            ("strategy", string("NoBackoff")),
+            ("delay", string("1s")),
+        ]))
+        .unwrap();
+
+        assert_eq!(checked.config, BackoffConfig::NoBackoff);
+        assert_eq!(
+            checked.unknown_keys[0].to_string(),
+            "unknown key `delay` for strategy `NoBackoff`"
+        );
+    }
+}
@@ -0,0 +1,374 @@
+use serde::de::{Error, Visitor};
+use serde::{Deserialize, Deserializer, Serializer};
+use std::fmt::{Display, Formatter};
+use std::time::Duration;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+/// Unit of durations given as bare numbers, e.g. `500` or `"500"` - see the `duration_unit` key of [crate::BackoffConfig].
+pub(crate) enum DurationUnit {
+    Milliseconds,
+    Seconds,
+}
+
+impl DurationUnit {
+    /// Converts `amount` of this unit to a [Duration], if it is non-negative and not too large.
+    pub(crate) fn duration(self, amount: f64) -> Result<Duration, DurationError> {
+        let secs = match self {
+            DurationUnit::Milliseconds => amount / 1000_f64,
+            DurationUnit::Seconds => amount,
+        };
+
+        Duration::try_from_secs_f64(secs).map_err(|_| DurationError {
+            input: amount.to_string(),
+            message: format!("must be a non-negative, finite and not too large number of {self}"),
+        })
+    }
+}
+
+/// Parses a bare number without a unit, e.g. `"500"` or `"1.5"`.
+pub(crate) fn bare_number(input: &str) -> Option<f64> {
+    input.trim().parse().ok()
+}
+
+impl Display for DurationUnit {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DurationUnit::Milliseconds => write!(f, "milliseconds"),
+            DurationUnit::Seconds => write!(f, "seconds"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+/// Describes why a duration could not be parsed - see [parse_duration].
+pub struct DurationError {
+    /// The rejected input.
+    pub input: String,
+
+    /// Human-readable description of the problem.
+    pub message: String,
+}
+
+impl Display for DurationError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "invalid duration `{}`: {}; expected a duration string (e.g. `500ms`, `1m 30s`) or an ISO 8601 duration \
+             (e.g. `PT0.5S`)",
+            self.input, self.message
+        )
+    }
+}
+
+impl std::error::Error for DurationError {}
+
+/// Parses a duration string, either in `duration-str` syntax (e.g. `500ms`, `1m 30s`, `1m+30`) or as an ISO 8601
+/// duration (e.g. `PT0.5S`, `P1DT2H`).
+///
+/// ISO 8601 years and months are rejected, as their length varies. Bare numbers (e.g. `"500"`) are rejected too, as
+/// their unit is ambiguous - the duration deserializers read them in their own unit instead, e.g.
+/// [deserialize_duration_millis].
+pub fn parse_duration(input: &str) -> Result<Duration, DurationError> {
+    let trimmed = input.trim();
+    let result = if bare_number(trimmed).is_some() {
+        Err("missing a unit, e.g. `500ms` or `30s`".to_string())
+    } else if trimmed.starts_with(['P', 'p']) {
+        parse_iso8601(trimmed)
+    } else {
+        duration_str::parse(trimmed).map_err(|e| e.to_string())
+    };
+
+    result.map_err(|message| DurationError {
+        input: input.to_string(),
+        message: message.lines().next().unwrap_or_default().to_string(),
+    })
+}
+
+/// Parses an ISO 8601 duration of weeks, days, hours, minutes and seconds, e.g. `P1W`, `P1DT12H`, `PT1M30.5S`.
+///
+/// Computed exactly (up to nanoseconds), so that every [Duration] round-trips - see [serialize_duration].
+fn parse_iso8601(input: &str) -> Result<Duration, String> {
+    let overflow = || "ISO 8601 duration is too large".to_string();
+    let mut rest = &input[1..];
+    let mut in_time = false;
+    let mut components = 0;
+    let mut total = Duration::ZERO;
+
+    while !rest.is_empty() {
+        if rest.starts_with(['T', 't']) {
+            if in_time {
+                return Err("ISO 8601 duration has more than one `T`".to_string());
+            }
+            in_time = true;
+            rest = &rest[1..];
+            continue;
+        }
+
+        let end = rest
+            .find(|c: char| !c.is_ascii_digit() && c != '.' && c != ',')
+            .ok_or("ISO 8601 duration component lacks a designator")?;
+        let (whole, fraction) = rest[..end]
+            .split_once(['.', ','])
+            .unwrap_or((&rest[..end], ""));
+        let invalid = || format!("invalid ISO 8601 duration component `{}`", &rest[..end]);
+        let whole = whole.parse::<u64>().map_err(|_| invalid())?;
+        let fraction_nanos = match fraction {
+            "" => 0,
+            _ if fraction.contains(['.', ',']) => return Err(invalid()),
+            _ => format!("{:0<9.9}", fraction)
+                .parse::<u64>()
+                .map_err(|_| invalid())?,
+        };
+        let designator = rest[end..].chars().next().unwrap_or_default();
+
+        let unit_secs: u32 = match (in_time, designator.to_ascii_uppercase()) {
+            (false, 'W') => 7 * 86_400,
+            (false, 'D') => 86_400,
+            (true, 'H') => 3_600,
+            (true, 'M') => 60,
+            (true, 'S') => 1,
+            (false, 'Y' | 'M') => {
+                return Err("ISO 8601 years and months are not supported".to_string());
+            }
+            _ => {
+                return Err(format!("unexpected ISO 8601 designator `{designator}`"));
+            }
+        };
+
+        let component = whole
+            .checked_mul(u64::from(unit_secs))
+            .map(Duration::from_secs)
+            .zip(Duration::from_nanos(fraction_nanos).checked_mul(unit_secs))
+            .and_then(|(whole, fraction)| whole.checked_add(fraction))
+            .ok_or_else(overflow)?;
+        total = total.checked_add(component).ok_or_else(overflow)?;
+        components += 1;
+        rest = &rest[end + designator.len_utf8()..];
+    }
+
+    if components == 0 {
+        return Err("ISO 8601 duration has no components".to_string());
+    }
+
+    Ok(total)
+}
+
+/// Serializes a [Duration] as a human-readable string accepted by [deserialize_duration], e.g. `500ms` or `1s 5ns`.
+///
+/// Durations too large for `duration-str` (centuries) are serialized as ISO 8601 seconds, e.g. `PT18446744073709551615S`.
+pub fn serialize_duration<S: Serializer>(
+    duration: &Duration,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    serializer.serialize_str(&format_duration(*duration))
+}
+
+/// Formats a [Duration] - see [serialize_duration].
+pub(crate) fn format_duration(duration: Duration) -> String {
+    let (secs, nanos) = (duration.as_secs(), duration.subsec_nanos());
+
+    if u64::try_from(duration.as_nanos()).is_err() {
+        return match nanos {
+            0 => format!("PT{secs}S"),
+            _ => format!("PT{secs}.{nanos:09}S"),
+        };
+    }
+
+    let subsec = if nanos % 1_000_000 == 0 {
+        format!("{}ms", nanos / 1_000_000)
+    } else {
+        format!("{nanos}ns")
+    };
+
+    match (secs, nanos) {
+        (0, 0) => "0s".to_string(),
+        (secs, 0) => format!("{secs}s"),
+        (0, _) => subsec,
+        (secs, _) => format!("{secs}s {subsec}"),
+    }
+}
+
+/// Deserializes a [Duration] from a string accepted by [parse_duration], or from a bare number of milliseconds - same
+/// as [deserialize_duration_millis].
+///
+/// Used for every duration field of this crate, where the `duration_unit` key of a [crate::BackoffConfig] switches bare
+/// numbers to seconds. Usable for user fields via `#[serde(deserialize_with = "backoff_config::deserialize_duration")]`.
+pub fn deserialize_duration<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Duration, D::Error> {
+    deserialize_duration_millis(deserializer)
+}
+
+/// Deserializes a [Duration] from a string accepted by [parse_duration], or from a bare number of milliseconds.
+///
+/// Bare numbers may be given as integers, floats or strings: `500`, `500.0` and `"500"` are all 500 milliseconds.
+/// Usable via `#[serde(deserialize_with = "backoff_config::deserialize_duration_millis")]`.
+pub fn deserialize_duration_millis<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Duration, D::Error> {
+    deserializer.deserialize_any(DurationVisitor(DurationUnit::Milliseconds))
+}
+
+/// Deserializes a [Duration] from a string accepted by [parse_duration], or from a bare number of seconds.
+///
+/// Bare numbers may be given as integers, floats or strings: `500`, `500.0` and `"500"` are all 500 seconds. Usable via
+/// `#[serde(deserialize_with = "backoff_config::deserialize_duration_secs")]`.
+pub fn deserialize_duration_secs<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Duration, D::Error> {
+    deserializer.deserialize_any(DurationVisitor(DurationUnit::Seconds))
+}
+
+/// Visits a duration string, or a bare number of the given unit.
+struct DurationVisitor(DurationUnit);
+
+impl<'de> Visitor<'de> for DurationVisitor {
+    type Value = Duration;
+
+    fn expecting(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(
+            f,
+            "a duration string (e.g. `500ms`, `1m 30s`), an ISO 8601 duration (e.g. `PT0.5S`) or a non-negative \
+             number of {} (e.g. `500`)",
+            self.0
+        )
+    }
+
+    fn visit_str<E: Error>(self, v: &str) -> Result<Duration, E> {
+        if let Some(amount) = bare_number(v) {
+            return self.visit_f64(amount);
+        }
+
+        parse_duration(v)
+            .map_err(|e| E::custom(format_args!("{e}, or a non-negative number of {}", self.0)))
+    }
+
+    fn visit_u64<E: Error>(self, v: u64) -> Result<Duration, E> {
+        self.visit_f64(v as f64)
+    }
+
+    fn visit_i64<E: Error>(self, v: i64) -> Result<Duration, E> {
+        self.visit_f64(v as f64)
+    }
+
+    fn visit_f64<E: Error>(self, v: f64) -> Result<Duration, E> {
+        self.0.duration(v).map_err(E::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde::Deserialize;
+    use serde::de::value::{
+        Error as ValueError, F64Deserializer, I64Deserializer, StrDeserializer,
+    };
+
+    fn from_str(s: &str) -> Result<Duration, ValueError> {
+        deserialize_duration(StrDeserializer::<ValueError>::new(s))
+    }
+
+    #[test]
+    fn parses_duration_str() {
+        assert_eq!(from_str("500ms"), Ok(Duration::from_millis(500)));
+        assert_eq!(from_str("1m 30s"), Ok(Duration::from_secs(90)));
+        assert_eq!(from_str("5s"), Ok(Duration::from_secs(5)));
+    }
+
+    #[test]
+    fn parses_iso8601() {
+        assert_eq!(from_str("PT0.5S"), Ok(Duration::from_millis(500)));
+        assert_eq!(from_str("PT1M30S"), Ok(Duration::from_secs(90)));
+        assert_eq!(from_str("pt2h"), Ok(Duration::from_secs(7_200)));
+        assert_eq!(from_str("P1DT1S"), Ok(Duration::from_secs(86_401)));
+        assert_eq!(from_str("P1W"), Ok(Duration::from_secs(604_800)));
+        assert_eq!(from_str("PT0,25S"), Ok(Duration::from_millis(250)));
+
+        for invalid in ["P", "PT", "P1M", "P1Y", "PT1D", "PT1", "P1DTT1S", "PT-1S"] {
+            assert!(from_str(invalid).is_err(), "{invalid}");
+        }
+    }
+
+    #[test]
+    fn parses_numbers_in_unit() {
+        let millis_from_i64 = |v| deserialize_duration(I64Deserializer::<ValueError>::new(v));
+        let millis_from_f64 = |v| deserialize_duration(F64Deserializer::<ValueError>::new(v));
+        let secs_from_i64 = |v| deserialize_duration_secs(I64Deserializer::<ValueError>::new(v));
+        let secs_from_f64 = |v| deserialize_duration_secs(F64Deserializer::<ValueError>::new(v));
+
+        assert_eq!(millis_from_i64(500), Ok(Duration::from_millis(500)));
+        assert_eq!(millis_from_f64(1.5), Ok(Duration::from_micros(1_500)));
+        assert_eq!(secs_from_i64(2), Ok(Duration::from_secs(2)));
+        assert_eq!(secs_from_f64(0.5), Ok(Duration::from_millis(500)));
+
+        // Strings of bare numbers are in the same unit as numbers.
+        assert_eq!(from_str("500"), Ok(Duration::from_millis(500)));
+        assert_eq!(from_str(" 1.5 "), Ok(Duration::from_micros(1_500)));
+        assert_eq!(
+            deserialize_duration_secs(StrDeserializer::<ValueError>::new("500")),
+            Ok(Duration::from_secs(500))
+        );
+        assert!(from_str("-500").is_err());
+        assert_eq!(
+            parse_duration("500").unwrap_err().message,
+            "missing a unit, e.g. `500ms` or `30s`"
+        );
+
+        assert!(millis_from_i64(-1).is_err());
+        assert!(millis_from_f64(f64::NAN).is_err());
+        assert!(
+            secs_from_i64(-1)
+                .unwrap_err()
+                .to_string()
+                .contains("number of seconds")
+        );
+    }
+
+    #[test]
+    fn serializes_round_trip() {
+        for duration in [
+            Duration::ZERO,
+            Duration::from_nanos(5),
+            Duration::from_millis(500),
+            Duration::from_secs(90),
+            Duration::new(1, 3),
+            Duration::new(2, 250_000_000),
+            Duration::from_secs(u64::MAX),
+            Duration::MAX,
+        ] {
+            let formatted = format_duration(duration);
+            assert_eq!(from_str(&formatted), Ok(duration), "{formatted}");
+        }
+
+        assert_eq!(format_duration(Duration::new(2, 250_000_000)), "2s 250ms");
+        assert_eq!(
+            format_duration(Duration::MAX),
+            "PT18446744073709551615.999999999S"
+        );
+    }
+
+    #[test]
+    fn lists_accepted_formats() {
+        assert_eq!(
+            from_str("P1Y").unwrap_err().to_string(),
+            "invalid duration `P1Y`: ISO 8601 years and months are not supported; expected a duration string \
+             (e.g. `500ms`, `1m 30s`) or an ISO 8601 duration (e.g. `PT0.5S`), or a non-negative number of \
+             milliseconds"
+        );
+
+        #[derive(Debug, Deserialize)]
+        #[allow(dead_code)]
+        struct Config {
+            #[serde(deserialize_with = "deserialize_duration")]
+            delay: Duration,
+        }
+
+        let error = serde_json::from_str::<Config>(r#"{ "delay": true }"#).unwrap_err();
+        assert!(
+            error
+                .to_string()
+                .contains("an ISO 8601 duration (e.g. `PT0.5S`)")
+        );
+    }
+}
@@ -0,0 +1,396 @@
+use crate::*;
+use serde::Serialize;
+use serde::ser::{Error, Impossible, SerializeStruct, Serializer};
+use std::fmt::{Display, Write};
+
+impl BackoffConfig {
+    /// Returns the environment variables that describe this [BackoffConfig], in the layout read by figment's
+    /// `Env::prefixed(..).split(separator)`.
+    ///
+    /// `prefix` is the full path of the config, e.g. `CONFIG__BACKOFF` for a `backoff` field read with
+    /// `Env::prefixed("CONFIG__").split("__")`. Keys are upper-cased and joined to `prefix` with `separator`:
+    ///
+    /// ```
+    /// use backoff_config::*;
+    ///
+    /// let config = BackoffConfig::Constant(ConstantBackoffConfig::default());
+    ///
+    /// assert_eq!(
+    ///     config.to_env_vars("CONFIG__BACKOFF", "__"),
+    ///     vec![
+    ///         ("CONFIG__BACKOFF__STRATEGY".to_string(), "Constant".to_string()),
+    ///         ("CONFIG__BACKOFF__DELAY".to_string(), "500ms".to_string()),
+    ///         ("CONFIG__BACKOFF__MAX_RETRIES".to_string(), "4".to_string()),
+    ///         ("CONFIG__BACKOFF__JITTER".to_string(), "true".to_string()),
+    ///     ]
+    /// );
+    /// ```
+    pub fn to_env_vars(&self, prefix: &str, separator: &str) -> Vec<(String, String)> {
+        let fields = self
+            .serialize(FieldsSerializer)
+            .expect("BackoffConfig serializes to a flat struct");
+
+        fields
+            .into_iter()
+            .filter_map(|(key, value)| Some((env_var_name(prefix, separator, key), value?)))
+            .collect()
+    }
+
+    /// Returns a reference of every environment variable accepted by each strategy, with its default value, in the
+    /// layout of [BackoffConfig::to_env_vars].
+    ///
+    /// The reference is a valid env file: each strategy is a commented section, and variables without a default would
+    /// be commented out, e.g.:
+    ///
+    /// ```text
+    /// # strategy = Constant (alias: fixed)
+    /// CONFIG__BACKOFF__STRATEGY=Constant
+    /// CONFIG__BACKOFF__DELAY=500ms
+    /// CONFIG__BACKOFF__MAX_RETRIES=4
+    /// CONFIG__BACKOFF__JITTER=true
+    /// ```
+    pub fn env_var_reference(prefix: &str, separator: &str) -> String {
+        let mut reference = String::new();
+
+        for (index, strategy) in STRATEGIES.iter().enumerate() {
+            if index > 0 {
+                reference.push('\n');
+            }
+
+            let aliases = STRATEGY_ALIASES
+                .iter()
+                .filter(|(_, canonical)| canonical == strategy)
+                .map(|(alias, _)| *alias)
+                .collect::<Vec<_>>();
+            let _ = match aliases.as_slice() {
+                [] => writeln!(reference, "# strategy = {strategy}"),
+                [alias] => writeln!(reference, "# strategy = {strategy} (alias: {alias})"),
+                aliases => writeln!(
+                    reference,
+                    "# strategy = {strategy} (aliases: {})",
+                    aliases.join(", ")
+                ),
+            };
+
+            let defaults = default_config(strategy).to_env_vars(prefix, separator);
+            let _ = writeln!(
+                reference,
+                "{}={strategy}",
+                env_var_name(prefix, separator, "strategy")
+            );
+
+            for field in strategy_fields(strategy) {
+                let name = env_var_name(prefix, separator, field);
+                let _ = match defaults.iter().find(|(key, _)| *key == name) {
+                    Some((_, default)) => writeln!(reference, "{name}={default}"),
+                    None => writeln!(reference, "# {name}="),
+                };
+            }
+        }
+
+        reference
+    }
+}
+
+/// Returns the name of the environment variable of `key` - see [BackoffConfig::to_env_vars].
+fn env_var_name(prefix: &str, separator: &str, key: &str) -> String {
+    match prefix {
+        "" => key.to_uppercase(),
+        prefix => format!("{prefix}{separator}{}", key.to_uppercase()),
+    }
+}
+
+/// Error of [FieldsSerializer] and [ValueSerializer] on anything but a flat struct of scalars.
+#[derive(Debug)]
+struct UnsupportedError(String);
+
+impl Display for UnsupportedError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl std::error::Error for UnsupportedError {}
+
+impl Error for UnsupportedError {
+    fn custom<T: Display>(msg: T) -> Self {
+        UnsupportedError(msg.to_string())
+    }
+}
+
+/// [Serializer] of a flat struct into its fields and their values, `None` for unset optional values.
+struct FieldsSerializer;
+
+/// [SerializeStruct] of [FieldsSerializer].
+struct Fields(Vec<(&'static str, Option<String>)>);
+
+impl SerializeStruct for Fields {
+    type Ok = Vec<(&'static str, Option<String>)>;
+    type Error = UnsupportedError;
+
+    fn serialize_field<T: ?Sized + Serialize>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<(), Self::Error> {
+        self.0.push((key, value.serialize(ValueSerializer)?));
+        Ok(())
+    }
+
+    fn end(self) -> Result<Self::Ok, Self::Error> {
+        Ok(self.0)
+    }
+}
+
+/// Implements the [Serializer] methods `$method`s by failing with `$expected`.
+macro_rules! unsupported {
+    ($expected:literal: $($method:ident($($arg:ty),*) -> $ok:ty;)*) => {
+        $(
+            fn $method(self, $(_: $arg),*) -> Result<$ok, Self::Error> {
+                Err(UnsupportedError(concat!("expected ", $expected).to_string()))
+            }
+        )*
+    };
+}
+
+impl Serializer for FieldsSerializer {
+    type Ok = Vec<(&'static str, Option<String>)>;
+    type Error = UnsupportedError;
+    type SerializeSeq = Impossible<Self::Ok, Self::Error>;
+    type SerializeTuple = Impossible<Self::Ok, Self::Error>;
+    type SerializeTupleStruct = Impossible<Self::Ok, Self::Error>;
+    type SerializeTupleVariant = Impossible<Self::Ok, Self::Error>;
+    type SerializeMap = Impossible<Self::Ok, Self::Error>;
+    type SerializeStruct = Fields;
+    type SerializeStructVariant = Impossible<Self::Ok, Self::Error>;
+
+    fn serialize_struct(self, _: &'static str, len: usize) -> Result<Fields, Self::Error> {
+        Ok(Fields(Vec::with_capacity(len)))
+    }
+
+    fn serialize_some<T: ?Sized + Serialize>(self, _: &T) -> Result<Self::Ok, Self::Error> {
+        Err(UnsupportedError("expected a struct".to_string()))
+    }
+
+    fn serialize_newtype_struct<T: ?Sized + Serialize>(
+        self,
+        _: &'static str,
+        value: &T,
+    ) -> Result<Self::Ok, Self::Error> {
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_variant<T: ?Sized + Serialize>(
+        self,
+        _: &'static str,
+        _: u32,
+        _: &'static str,
+        _: &T,
+    ) -> Result<Self::Ok, Self::Error> {
+        Err(UnsupportedError("expected a struct".to_string()))
+    }
+
+    unsupported! { "a struct":
+        serialize_bool(bool) -> Self::Ok;
+        serialize_i8(i8) -> Self::Ok;
+        serialize_i16(i16) -> Self::Ok;
+        serialize_i32(i32) -> Self::Ok;
+        serialize_i64(i64) -> Self::Ok;
+        serialize_u8(u8) -> Self::Ok;
+        serialize_u16(u16) -> Self::Ok;
+        serialize_u32(u32) -> Self::Ok;
+        serialize_u64(u64) -> Self::Ok;
+        serialize_f32(f32) -> Self::Ok;
+        serialize_f64(f64) -> Self::Ok;
+        serialize_char(char) -> Self::Ok;
+        serialize_str(&str) -> Self::Ok;
+        serialize_bytes(&[u8]) -> Self::Ok;
+        serialize_none() -> Self::Ok;
+        serialize_unit() -> Self::Ok;
+        serialize_unit_struct(&'static str) -> Self::Ok;
+        serialize_unit_variant(&'static str, u32, &'static str) -> Self::Ok;
+        serialize_seq(Option<usize>) -> Self::SerializeSeq;
+        serialize_tuple(usize) -> Self::SerializeTuple;
+        serialize_tuple_struct(&'static str, usize) -> Self::SerializeTupleStruct;
+        serialize_tuple_variant(&'static str, u32, &'static str, usize) -> Self::SerializeTupleVariant;
+        serialize_map(Option<usize>) -> Self::SerializeMap;
+        serialize_struct_variant(&'static str, u32, &'static str, usize) -> Self::SerializeStructVariant;
+    }
+}
+
+/// [Serializer] of a scalar into its env var value, `None` if unset.
+struct ValueSerializer;
+
+impl Serializer for ValueSerializer {
+    type Ok = Option<String>;
+    type Error = UnsupportedError;
+    type SerializeSeq = Impossible<Self::Ok, Self::Error>;
+    type SerializeTuple = Impossible<Self::Ok, Self::Error>;
+    type SerializeTupleStruct = Impossible<Self::Ok, Self::Error>;
+    type SerializeTupleVariant = Impossible<Self::Ok, Self::Error>;
+    type SerializeMap = Impossible<Self::Ok, Self::Error>;
+    type SerializeStruct = Impossible<Self::Ok, Self::Error>;
+    type SerializeStructVariant = Impossible<Self::Ok, Self::Error>;
+
+    fn serialize_bool(self, v: bool) -> Result<Self::Ok, Self::Error> {
+        Ok(Some(v.to_string()))
+    }
+
+    fn serialize_i64(self, v: i64) -> Result<Self::Ok, Self::Error> {
+        Ok(Some(v.to_string()))
+    }
+
+    fn serialize_u64(self, v: u64) -> Result<Self::Ok, Self::Error> {
+        Ok(Some(v.to_string()))
+    }
+
+    fn serialize_f64(self, v: f64) -> Result<Self::Ok, Self::Error> {
+        Ok(Some(v.to_string()))
+    }
+
+    fn serialize_f32(self, v: f32) -> Result<Self::Ok, Self::Error> {
+        Ok(Some(v.to_string()))
+    }
+
+    fn serialize_i8(self, v: i8) -> Result<Self::Ok, Self::Error> {
+        self.serialize_i64(v.into())
+    }
+
+    fn serialize_i16(self, v: i16) -> Result<Self::Ok, Self::Error> {
+        self.serialize_i64(v.into())
+    }
+
+    fn serialize_i32(self, v: i32) -> Result<Self::Ok, Self::Error> {
+        self.serialize_i64(v.into())
+    }
+
+    fn serialize_u8(self, v: u8) -> Result<Self::Ok, Self::Error> {
+        self.serialize_u64(v.into())
+    }
+
+    fn serialize_u16(self, v: u16) -> Result<Self::Ok, Self::Error> {
+        self.serialize_u64(v.into())
+    }
+
+    fn serialize_u32(self, v: u32) -> Result<Self::Ok, Self::Error> {
+        self.serialize_u64(v.into())
+    }
+
+    fn serialize_char(self, v: char) -> Result<Self::Ok, Self::Error> {
+        Ok(Some(v.to_string()))
+    }
+
+    fn serialize_str(self, v: &str) -> Result<Self::Ok, Self::Error> {
+        Ok(Some(v.to_string()))
+    }
+
+    fn serialize_none(self) -> Result<Self::Ok, Self::Error> {
+        Ok(None)
+    }
+
+    fn serialize_some<T: ?Sized + Serialize>(self, value: &T) -> Result<Self::Ok, Self::Error> {
+        value.serialize(self)
+    }
+
+    fn serialize_unit_variant(
+        self,
+        _: &'static str,
+        _: u32,
+        variant: &'static str,
+    ) -> Result<Self::Ok, Self::Error> {
+        Ok(Some(variant.to_string()))
+    }
+
+    fn serialize_newtype_struct<T: ?Sized + Serialize>(
+        self,
+        _: &'static str,
+        value: &T,
+    ) -> Result<Self::Ok, Self::Error> {
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_variant<T: ?Sized + Serialize>(
+        self,
+        _: &'static str,
+        _: u32,
+        _: &'static str,
+        _: &T,
+    ) -> Result<Self::Ok, Self::Error> {
+        Err(UnsupportedError("expected a scalar".to_string()))
+    }
+
+    unsupported! { "a scalar":
+        serialize_bytes(&[u8]) -> Self::Ok;
+        serialize_unit() -> Self::Ok;
+        serialize_unit_struct(&'static str) -> Self::Ok;
+        serialize_seq(Option<usize>) -> Self::SerializeSeq;
+        serialize_tuple(usize) -> Self::SerializeTuple;
+        serialize_tuple_struct(&'static str, usize) -> Self::SerializeTupleStruct;
+        serialize_tuple_variant(&'static str, u32, &'static str, usize) -> Self::SerializeTupleVariant;
+        serialize_map(Option<usize>) -> Self::SerializeMap;
+        serialize_struct(&'static str, usize) -> Self::SerializeStruct;
+        serialize_struct_variant(&'static str, u32, &'static str, usize) -> Self::SerializeStructVariant;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    #[test]
+    fn exports_every_set_field() {
+        let config = BackoffConfig::Exponential(ExponentialBackoffConfig {
+            initial_delay: Duration::from_millis(250),
+            factor: 1.5,
+            max_delay: Duration::from_secs(90),
+            max_retries: 7,
+            max_total_delay: Duration::MAX,
+            jitter: Jitter::Seeded(42),
+        });
+
+        assert_eq!(
+            config.to_env_vars("APP", "_"),
+            [
+                ("APP_STRATEGY", "Exponential"),
+                ("APP_INITIAL_DELAY", "250ms"),
+                ("APP_FACTOR", "1.5"),
+                ("APP_MAX_DELAY", "90s"),
+                ("APP_MAX_RETRIES", "7"),
+                ("APP_MAX_TOTAL_DELAY", "PT18446744073709551615.999999999S"),
+                ("APP_JITTER", "42"),
+            ]
+            .map(|(key, value)| (key.to_string(), value.to_string()))
+        );
+
+        assert_eq!(
+            BackoffConfig::NoBackoff.to_env_vars("", "__"),
+            vec![("STRATEGY".to_string(), "NoBackoff".to_string())]
+        );
+    }
+
+    #[test]
+    fn lists_every_field_of_every_strategy() {
+        let reference = BackoffConfig::env_var_reference("CONFIG__BACKOFF", "__");
+
+        for strategy in STRATEGIES {
+            assert!(reference.contains(&format!("CONFIG__BACKOFF__STRATEGY={strategy}\n")));
+
+            for field in strategy_fields(strategy) {
+                assert!(reference.contains(&format!("CONFIG__BACKOFF__{}", field.to_uppercase())));
+            }
+        }
+
+        assert!(reference.starts_with(
+            "# strategy = Constant (alias: fixed)\n\
+             CONFIG__BACKOFF__STRATEGY=Constant\n\
+             CONFIG__BACKOFF__DELAY=500ms\n\
+             CONFIG__BACKOFF__MAX_RETRIES=4\n\
+             CONFIG__BACKOFF__JITTER=true\n\n"
+        ));
+        assert!(reference.ends_with(
+            "# strategy = NoBackoff (aliases: none, off, disabled)\n\
+             CONFIG__BACKOFF__STRATEGY=NoBackoff\n"
+        ));
+    }
+}
@@ -0,0 +1,339 @@
+use crate::*;
+use serde::Deserialize;
+#[cfg(feature = "hedging")]
+use {
+    backon::BackoffBuilder,
+    std::future::{Future, poll_fn},
+    std::pin::Pin,
+    std::task::Poll,
+};
+
+#[derive(Debug, smart_default::SmartDefault, Clone, Deserialize, PartialEq)]
+/// Configuration for hedged requests - see `HedgingConfig::execute` (requires the `hedging` feature).
+///
+/// Instead of retrying sequentially, a new attempt is started whenever the previous one has not answered within the
+/// next [HedgingConfig::hedge_delay]. The first successful attempt wins and all other attempts are cancelled.
+pub struct HedgingConfig {
+    /// Schedule of delays between starting consecutive attempts. Once exhausted, no more attempts are started.
+    ///
+    /// Defaults to a constant `100 millis` delay - see [defaults::hedge_delay].
+    #[serde(default = "defaults::hedge_delay")]
+    #[default(defaults::hedge_delay())]
+    pub hedge_delay: BackoffConfig,
+
+    /// Maximum total amount of attempts started, including the first one and those started after non-fatal failures.
+    ///
+    /// Defaults to `3` - see [defaults::max_total_attempts].
+    #[serde(default = "defaults::max_total_attempts")]
+    #[default(defaults::max_total_attempts())]
+    pub max_total_attempts: usize,
+
+    /// Statuses of failed attempts that start the next attempt immediately, compared case-insensitively.
+    ///
+    /// Failures with any other status are fatal: all attempts are cancelled and the failure is returned.
+    ///
+    /// Defaults to no statuses - see [defaults::non_fatal_statuses].
+    #[serde(default = "defaults::non_fatal_statuses")]
+    #[default(defaults::non_fatal_statuses())]
+    pub non_fatal_statuses: Vec<String>,
+}
+
+impl HedgingConfig {
+    /// Checks that this [HedgingConfig] describes a sensible hedging policy.
+    pub fn validate(&self) -> Result<(), ValidationError> {
+        if self.max_total_attempts == 0 {
+            return Err(ValidationError::new(
+                "max_total_attempts",
+                "must be greater than zero",
+            ));
+        }
+
+        self.hedge_delay.validate()
+    }
+
+    /// Returns whether a failure with the given status starts the next attempt immediately.
+    pub fn is_non_fatal(&self, status: &str) -> bool {
+        self.non_fatal_statuses
+            .iter()
+            .any(|non_fatal| non_fatal.eq_ignore_ascii_case(status))
+    }
+
+    /// Runs hedged attempts created by `attempt`, returning the first success.
+    ///
+    /// `attempt` is called with the index of the attempt to start. Failed attempts are classified via `status` - see
+    /// [HedgingConfig::non_fatal_statuses]. Once an attempt succeeds or fails fatally, all other attempts are dropped,
+    /// which cancels them. If every attempt fails non-fatally, the last failure is returned.
+    ///
+    /// Hedge delays are measured with [tokio::time], so this must run within a Tokio runtime.
+    #[cfg(feature = "hedging")]
+    pub async fn execute<T, E, S, F, Fut>(
+        &self,
+        mut attempt: F,
+        status: impl Fn(&E) -> S,
+    ) -> Result<T, E>
+    where
+        S: AsRef<str>,
+        F: FnMut(usize) -> Fut,
+        Fut: Future<Output = Result<T, E>>,
+    {
+        let max_total_attempts = self.max_total_attempts.max(1);
+        let mut delays = self.hedge_delay.build();
+        let mut in_flight = vec![Box::pin(attempt(0))];
+        let mut started = 1;
+        let mut next_hedge = schedule_hedge(&mut delays, started, max_total_attempts);
+
+        loop {
+            let event = poll_fn(|cx| {
+                for (index, future) in in_flight.iter_mut().enumerate() {
+                    if let Poll::Ready(result) = future.as_mut().poll(cx) {
+                        return Poll::Ready(Event::Completed(index, result));
+                    }
+                }
+
+                if let Some(sleep) = next_hedge.as_mut()
+                    && sleep.as_mut().poll(cx).is_ready()
+                {
+                    return Poll::Ready(Event::Hedge);
+                }
+
+                Poll::Pending
+            })
+            .await;
+
+            match event {
+                Event::Completed(_, Ok(value)) => return Ok(value),
+
+                Event::Completed(index, Err(error)) => {
+                    drop(in_flight.swap_remove(index));
+
+                    if !self.is_non_fatal(status(&error).as_ref()) {
+                        return Err(error);
+                    }
+
+                    if started < max_total_attempts {
+                        in_flight.push(Box::pin(attempt(started)));
+                        started += 1;
+                        next_hedge = schedule_hedge(&mut delays, started, max_total_attempts);
+                    } else if in_flight.is_empty() {
+                        return Err(error);
+                    }
+                }
+
+                Event::Hedge => {
+                    in_flight.push(Box::pin(attempt(started)));
+                    started += 1;
+                    next_hedge = schedule_hedge(&mut delays, started, max_total_attempts);
+                }
+            }
+        }
+    }
+}
+
+#[cfg(feature = "hedging")]
+enum Event<T, E> {
+    Completed(usize, Result<T, E>),
+    Hedge,
+}
+
+/// Schedules the next hedge, unless [HedgingConfig::max_total_attempts] were started or the hedge delays are exhausted.
+#[cfg(feature = "hedging")]
+fn schedule_hedge(
+    delays: &mut Backoff,
+    started: usize,
+    max_total_attempts: usize,
+) -> Option<Pin<Box<tokio::time::Sleep>>> {
+    if started >= max_total_attempts {
+        return None;
+    }
+
+    delays
+        .next()
+        .map(|delay| Box::pin(tokio::time::sleep(delay)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn defaults() {
+        assert_eq!(
+            HedgingConfig::default(),
+            HedgingConfig {
+                hedge_delay: defaults::hedge_delay(),
+                max_total_attempts: defaults::max_total_attempts(),
+                non_fatal_statuses: defaults::non_fatal_statuses(),
+            }
+        );
+        assert_eq!(HedgingConfig::default().validate(), Ok(()));
+        assert_eq!(
+            HedgingConfig {
+                max_total_attempts: 0,
+                ..Default::default()
+            }
+            .validate()
+            .unwrap_err()
+            .field,
+            "max_total_attempts"
+        );
+    }
+
+    #[test]
+    fn is_non_fatal_ignores_case() {
+        let config = HedgingConfig {
+            non_fatal_statuses: vec!["UNAVAILABLE".to_string()],
+            ..Default::default()
+        };
+
+        assert!(config.is_non_fatal("unavailable"));
+        assert!(!config.is_non_fatal("INTERNAL"));
+    }
+
+    #[cfg(feature = "hedging")]
+    mod execute {
+        use super::*;
+        use std::sync::Arc;
+        use std::sync::atomic::{AtomicUsize, Ordering};
+        use std::time::Duration;
+        use tokio::time::{Instant, sleep};
+
+        fn config(max_total_attempts: usize) -> HedgingConfig {
+            HedgingConfig {
+                hedge_delay: BackoffConfig::Constant(ConstantBackoffConfig {
+                    delay: Duration::from_millis(100),
+                    max_retries: 10,
+                    jitter: Jitter::Disabled,
+                }),
+                max_total_attempts,
+                non_fatal_statuses: vec!["UNAVAILABLE".to_string()],
+            }
+        }
+
+        /// Increments the counter when dropped, i.e. when an attempt finishes or is cancelled.
+        struct DropCounter(Arc<AtomicUsize>);
+
+        impl Drop for DropCounter {
+            fn drop(&mut self) {
+                self.0.fetch_add(1, Ordering::SeqCst);
+            }
+        }
+
+        #[tokio::test(start_paused = true)]
+        async fn hedge_wins_and_cancels_slow_attempt() {
+            let start = Instant::now();
+            let dropped = Arc::new(AtomicUsize::new(0));
+
+            let result = config(3)
+                .execute(
+                    |attempt| {
+                        let guard = DropCounter(dropped.clone());
+                        async move {
+                            let _guard = guard;
+                            let latency = if attempt == 0 { 1000 } else { 50 };
+                            sleep(Duration::from_millis(latency)).await;
+                            Ok::<_, &str>(attempt)
+                        }
+                    },
+                    |e: &&str| *e,
+                )
+                .await;
+
+            assert_eq!(result, Ok(1));
+            assert_eq!(start.elapsed(), Duration::from_millis(150));
+            assert_eq!(dropped.load(Ordering::SeqCst), 2);
+        }
+
+        #[tokio::test(start_paused = true)]
+        async fn starts_at_most_max_total_attempts() {
+            let started = Arc::new(AtomicUsize::new(0));
+            let start = Instant::now();
+
+            let result = config(2)
+                .execute(
+                    |attempt| {
+                        started.fetch_add(1, Ordering::SeqCst);
+                        async move {
+                            sleep(Duration::from_millis(1000)).await;
+                            Ok::<_, &str>(attempt)
+                        }
+                    },
+                    |e: &&str| *e,
+                )
+                .await;
+
+            assert_eq!(result, Ok(0));
+            assert_eq!(start.elapsed(), Duration::from_millis(1000));
+            assert_eq!(started.load(Ordering::SeqCst), 2);
+        }
+
+        #[tokio::test(start_paused = true)]
+        async fn non_fatal_failure_starts_next_attempt_immediately() {
+            let start = Instant::now();
+
+            let result = config(3)
+                .execute(
+                    |attempt| async move {
+                        sleep(Duration::from_millis(10)).await;
+                        if attempt < 2 {
+                            Err("unavailable")
+                        } else {
+                            Ok(attempt)
+                        }
+                    },
+                    |e: &&str| *e,
+                )
+                .await;
+
+            assert_eq!(result, Ok(2));
+            assert_eq!(start.elapsed(), Duration::from_millis(30));
+        }
+
+        #[tokio::test(start_paused = true)]
+        async fn fatal_failure_cancels_other_attempts() {
+            let start = Instant::now();
+
+            let result = config(3)
+                .execute(
+                    |attempt| async move {
+                        if attempt == 0 {
+                            sleep(Duration::from_millis(1000)).await;
+                            Ok(attempt)
+                        } else {
+                            sleep(Duration::from_millis(10)).await;
+                            Err("internal")
+                        }
+                    },
+                    |e: &&str| *e,
+                )
+                .await;
+
+            assert_eq!(result, Err("internal"));
+            assert_eq!(start.elapsed(), Duration::from_millis(110));
+        }
+
+        #[tokio::test(start_paused = true)]
+        async fn returns_last_failure_when_all_attempts_fail() {
+            let started = Arc::new(AtomicUsize::new(0));
+
+            let result = config(2)
+                .execute(
+                    |attempt| {
+                        started.fetch_add(1, Ordering::SeqCst);
+                        async move {
+                            sleep(Duration::from_millis(10)).await;
+                            Err::<(), _>(if attempt == 0 {
+                                "unavailable"
+                            } else {
+                                "UNAVAILABLE"
+                            })
+                        }
+                    },
+                    |e: &&str| *e,
+                )
+                .await;
+
+            assert_eq!(result, Err("UNAVAILABLE"));
+            assert_eq!(started.load(Ordering::SeqCst), 2);
+        }
+    }
+}
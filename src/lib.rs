@@ -6,14 +6,68 @@
 //! environment variables or configuration files by:
 //!
 //! - Unifying the backoff strategies provided by the [backon](https://crates.io/crates/backon) retry crate into a single
-//!   enum (see [BackoffConfig]).
+//!   enum (see [BackoffConfig]), along with adaptive (AIMD), randomized exponential, polynomial and uniform random
+//!   strategies.
 //!
 //! - Implementing [serde::Deserialize] to support loading strategies from various
-//!   configuration sources. [std::time::Duration] values are deserialized using human-readable formats (e.g. `5s`, `150 ms`).
+//!   configuration sources. [std::time::Duration] values are deserialized using human-readable formats (e.g. `5s`, `150 ms`),
+//!   ISO 8601 (e.g. `PT0.5S`) or bare numbers of milliseconds (`500` or `"500"`) - see [deserialize_duration]. A
+//!   `duration_unit = "seconds"` key switches the bare numbers of a [BackoffConfig] to seconds, and user fields can
+//!   take bare numbers of seconds via [deserialize_duration_secs].
 //!
 //! See [examples](https://github.com/yevtyushkin/backoff-config/tree/main/examples) and [tests](https://github.com/yevtyushkin/backoff-config/tree/main/tests) for example configuration formats.
+//!
+//! ## Features
+//!
+//! - `cli`: enables the `backoff-config` binary, which lints and previews the [BackoffConfig]s of a TOML / JSON / YAML
+//!   file or of environment variables, failing if any config is invalid or exceeds a time budget.
+//! - `hedging`: enables `HedgingConfig::execute`, an async executor of hedged requests backed by `tokio`.
+//! - `shared`: enables `SharedBackoffConfig`, a hot-reloadable handle to a [BackoffConfig].
+//! - `test-util`: enables `RecordingSleeper`, a backon sleeper that records delays instead of sleeping, with assertions
+//!   against the expected schedule of a [BackoffConfig].
+//! - `watch`: enables `BackoffConfigWatcher`, which reloads a [BackoffConfig] from a TOML / JSON file into a
+//!   `SharedBackoffConfig`.
+mod adaptive;
 mod backoff;
 mod backoff_config;
+mod bounds;
+mod circuit_breaker;
+mod clock;
+mod deserialization;
+mod duration;
+mod env_vars;
+mod hedging;
+mod migration;
+mod retry_budget;
+mod retry_report;
+mod schedule;
+#[cfg(feature = "shared")]
+mod shared_backoff_config;
+mod snapshot;
+#[cfg(feature = "test-util")]
+mod test_util;
+mod validation;
+#[cfg(feature = "watch")]
+mod watch;
 
+pub use crate::adaptive::*;
 pub use crate::backoff::*;
 pub use crate::backoff_config::*;
+pub use crate::bounds::*;
+pub use crate::circuit_breaker::*;
+pub use crate::clock::*;
+pub use crate::deserialization::*;
+pub use crate::duration::*;
+pub use crate::hedging::*;
+pub use crate::migration::*;
+pub use crate::retry_budget::*;
+pub use crate::retry_report::*;
+pub use crate::schedule::*;
+#[cfg(feature = "shared")]
+pub use crate::shared_backoff_config::*;
+pub use crate::snapshot::*;
+#[cfg(feature = "test-util")]
+pub use crate::test_util::*;
+pub use crate::validation::*;
+#[cfg(feature = "watch")]
+pub use crate::watch::*;
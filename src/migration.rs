@@ -0,0 +1,360 @@
+use crate::deserialization::Content;
+use serde::de::Error;
+use std::fmt::{Display, Formatter};
+
+/// Current version of the [crate::BackoffConfig] schema, as set by the optional `version` key.
+///
+/// Version history:
+///
+/// - `1`: jitter is configured by `jitter_enabled` and `jitter_seed`.
+/// - `2`: jitter is configured by a single `jitter` key - see [crate::Jitter].
+pub const CURRENT_SCHEMA_VERSION: u32 = 2;
+
+/// Entries of a config map.
+type Entries = Vec<(Content, Content)>;
+
+/// Migration of a config from the previous schema version to `version`.
+struct Migration {
+    /// Schema version the migration upgrades to.
+    version: u32,
+
+    /// Keys only valid before `version`, used to detect the version of unversioned configs.
+    legacy_keys: &'static [&'static str],
+
+    /// Upgrades the entries of a config whose valid keys are `fields`, reporting deprecations.
+    migrate: fn(&mut Entries, fields: &[&str], &mut Vec<Deprecation>),
+}
+
+/// Migrations in order of their versions.
+const MIGRATIONS: &[Migration] = &[Migration {
+    version: 2,
+    legacy_keys: &["jitter_enabled", "jitter_seed"],
+    migrate: merge_jitter_keys,
+}];
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+/// Deprecated key of a [crate::BackoffConfig] that was migrated to the [CURRENT_SCHEMA_VERSION] - see
+/// [crate::CheckedBackoffConfig::deprecations].
+pub struct Deprecation {
+    /// The deprecated key, or `version` for an outdated schema version.
+    pub key: String,
+
+    /// Schema version that deprecated the key.
+    pub since_version: u32,
+
+    /// How to replace the key.
+    pub replacement: String,
+}
+
+impl Display for Deprecation {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "`{}` is deprecated since schema version {}, {}",
+            self.key, self.since_version, self.replacement
+        )
+    }
+}
+
+/// Removes the `version` key from `entries` and upgrades them to the [CURRENT_SCHEMA_VERSION].
+///
+/// Configs without a `version` are assumed to be of the oldest version whose legacy keys they use, or of the current
+/// version otherwise.
+pub(crate) fn migrate<E: Error>(
+    entries: &mut Entries,
+    fields: &[&str],
+) -> Result<Vec<Deprecation>, E> {
+    let mut deprecations = Vec::new();
+
+    let version = match take(entries, "version") {
+        Some(version) => {
+            let version = parse_version(version)?;
+            if version < CURRENT_SCHEMA_VERSION {
+                deprecations.push(Deprecation {
+                    key: "version".to_string(),
+                    since_version: CURRENT_SCHEMA_VERSION,
+                    replacement: format!(
+                        "upgrade from version {version} to {CURRENT_SCHEMA_VERSION}"
+                    ),
+                });
+            }
+            version
+        }
+
+        None => MIGRATIONS
+            .iter()
+            .find(|migration| {
+                entries.iter().any(|(key, _)| {
+                    key.as_str()
+                        .is_some_and(|key| migration.legacy_keys.contains(&key))
+                })
+            })
+            .map_or(CURRENT_SCHEMA_VERSION, |migration| migration.version - 1),
+    };
+
+    for migration in MIGRATIONS
+        .iter()
+        .filter(|migration| migration.version > version)
+    {
+        (migration.migrate)(entries, fields, &mut deprecations);
+    }
+
+    Ok(deprecations)
+}
+
+/// Parses the value of the `version` key.
+fn parse_version<E: Error>(version: Content) -> Result<u32, E> {
+    let parsed = match &version {
+        Content::U64(version) => u32::try_from(*version).ok(),
+        Content::I64(version) => u32::try_from(*version).ok(),
+        Content::String(version) => version.trim().parse().ok(),
+        _ => None,
+    };
+
+    match parsed {
+        Some(version @ 1..=CURRENT_SCHEMA_VERSION) => Ok(version),
+        _ => Err(E::invalid_value(
+            version.unexpected(),
+            &format!("a schema version from 1 to {CURRENT_SCHEMA_VERSION}").as_str(),
+        )),
+    }
+}
+
+/// Removes the entry of `key` from `entries`, returning its value.
+fn take(entries: &mut Entries, key: &str) -> Option<Content> {
+    let position = entries.iter().position(|(k, _)| k.as_str() == Some(key))?;
+    Some(entries.remove(position).1)
+}
+
+/// Version 2: merges `jitter_enabled` and `jitter_seed` into `jitter`.
+fn merge_jitter_keys(entries: &mut Entries, fields: &[&str], deprecations: &mut Vec<Deprecation>) {
+    if !fields.contains(&"jitter") {
+        return;
+    }
+
+    let enabled = take(entries, "jitter_enabled");
+    let seed = take(entries, "jitter_seed");
+    let legacy_keys = [
+        ("jitter_enabled", enabled.is_some()),
+        ("jitter_seed", seed.is_some()),
+    ];
+    let seed = seed.filter(|seed| *seed != Content::Unit);
+    let disabled = match &enabled {
+        Some(Content::Bool(enabled)) => !enabled,
+        Some(Content::String(enabled)) => enabled.trim().eq_ignore_ascii_case("false"),
+        _ => false,
+    };
+
+    let jitter = match (enabled, seed) {
+        (Some(enabled), _) if disabled => Some(enabled),
+        (_, Some(seed)) => Some(seed),
+        (enabled, None) => enabled,
+    };
+
+    let replacement = match jitter {
+        _ if entries
+            .iter()
+            .any(|(key, _)| key.as_str() == Some("jitter")) =>
+        {
+            "ignored in favour of `jitter`".to_string()
+        }
+        Some(jitter) => {
+            let replacement = format!("use `jitter = {}` instead", display(&jitter));
+            entries.push((Content::String("jitter".to_string()), jitter));
+            replacement
+        }
+        None => "use `jitter` instead".to_string(),
+    };
+
+    for (key, _) in legacy_keys.iter().filter(|(_, present)| *present) {
+        deprecations.push(Deprecation {
+            key: key.to_string(),
+            since_version: 2,
+            replacement: replacement.clone(),
+        });
+    }
+}
+
+/// Displays a scalar `content` as in a config file.
+fn display(content: &Content) -> String {
+    match content {
+        Content::Bool(v) => v.to_string(),
+        Content::U64(v) => v.to_string(),
+        Content::I64(v) => v.to_string(),
+        Content::F64(v) => v.to_string(),
+        Content::String(v) => format!("{v:?}"),
+        Content::Some(v) => display(v),
+        _ => "...".to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::*;
+    use serde::Deserialize;
+    use serde::de::IntoDeserializer;
+    use serde::de::value::Error as ValueError;
+
+    fn map(entries: &[(&str, Content)]) -> Content {
+        Content::Map(
+            entries
+                .iter()
+                .map(|(key, value)| (Content::String(key.to_string()), value.clone()))
+                .collect(),
+        )
+    }
+
+    fn string(s: &str) -> Content {
+        Content::String(s.to_string())
+    }
+
+    fn migrated(content: Content) -> Result<CheckedBackoffConfig, ValueError> {
+        CheckedBackoffConfig::deserialize(content.into_deserializer())
+    }
+
+    fn deprecation(key: &str, since_version: u32, replacement: &str) -> Deprecation {
+        Deprecation {
+            key: key.to_string(),
+            since_version,
+            replacement: replacement.to_string(),
+        }
+    }
+
+    fn constant_jitter(config: &BackoffConfig) -> Jitter {
+        match config {
+            BackoffConfig::Constant(config) => config.jitter,
+            config => panic!("expected a constant config, got {config:?}"),
+        }
+    }
+
+    #[test]
+    fn migrates_unversioned_legacy_jitter_keys() {
+        let checked = migrated(map(&[
+            ("strategy", string("Constant")),
+            ("jitter_enabled", Content::Bool(true)),
+            ("jitter_seed", Content::U64(7)),
+        ]))
+        .unwrap();
+
+        assert_eq!(
+            checked.config,
+            BackoffConfig::Constant(ConstantBackoffConfig {
+                jitter: Jitter::Seeded(7),
+                ..Default::default()
+            })
+        );
+        assert_eq!(checked.unknown_keys, vec![]);
+        assert_eq!(
+            checked.deprecations,
+            vec![
+                deprecation("jitter_enabled", 2, "use `jitter = 7` instead"),
+                deprecation("jitter_seed", 2, "use `jitter = 7` instead"),
+            ]
+        );
+    }
+
+    #[test]
+    fn disabled_jitter_takes_precedence_over_seed() {
+        let checked = migrated(map(&[
+            ("strategy", string("Fibonacci")),
+            ("jitter_enabled", Content::Bool(false)),
+            ("jitter_seed", Content::U64(7)),
+        ]))
+        .unwrap();
+
+        assert_eq!(
+            checked.config,
+            BackoffConfig::Fibonacci(FibonacciBackoffConfig {
+                jitter: Jitter::Disabled,
+                ..Default::default()
+            })
+        );
+        assert_eq!(
+            checked.deprecations[0].to_string(),
+            "`jitter_enabled` is deprecated since schema version 2, use `jitter = false` instead"
+        );
+    }
+
+    #[test]
+    fn legacy_keys_are_ignored_in_favour_of_jitter() {
+        let checked = migrated(map(&[
+            ("strategy", string("Constant")),
+            ("jitter", Content::Bool(false)),
+            ("jitter_seed", Content::U64(7)),
+        ]))
+        .unwrap();
+
+        assert_eq!(constant_jitter(&checked.config), Jitter::Disabled);
+        assert_eq!(
+            checked.deprecations,
+            vec![deprecation(
+                "jitter_seed",
+                2,
+                "ignored in favour of `jitter`"
+            )]
+        );
+    }
+
+    #[test]
+    fn explicit_versions() {
+        let checked = migrated(map(&[
+            ("version", Content::U64(1)),
+            ("strategy", string("Constant")),
+            ("jitter_enabled", string("false")),
+        ]))
+        .unwrap();
+        assert_eq!(constant_jitter(&checked.config), Jitter::Disabled);
+        assert_eq!(
+            checked.deprecations,
+            vec![
+                deprecation("version", 2, "upgrade from version 1 to 2"),
+                deprecation("jitter_enabled", 2, "use `jitter = \"false\"` instead"),
+            ]
+        );
+
+        let checked = migrated(map(&[
+            ("version", string("2")),
+            ("strategy", string("Constant")),
+            ("jitter_enabled", Content::Bool(false)),
+        ]))
+        .unwrap();
+        assert_eq!(checked.config, BackoffConfig::Constant(Default::default()));
+        assert_eq!(checked.deprecations, vec![]);
+        assert_eq!(
+            checked.unknown_keys,
+            vec![UnknownKey {
+                key: "jitter_enabled".to_string(),
+                strategy: "Constant",
+                suggestion: None,
+            }]
+        );
+
+        for version in [Content::U64(0), Content::I64(3), string("latest")] {
+            let error = migrated(map(&[
+                ("version", version),
+                ("strategy", string("Constant")),
+            ]))
+            .unwrap_err();
+            assert!(
+                error
+                    .to_string()
+                    .contains("expected a schema version from 1 to 2"),
+                "{error}"
+            );
+        }
+    }
+
+    #[test]
+    fn strategies_without_jitter_keep_legacy_keys_unknown() {
+        let checked = migrated(map(&[
+            ("strategy", string("NoBackoff")),
+            ("jitter_enabled", Content::Bool(true)),
+        ]))
+        .unwrap();
+
+        assert_eq!(checked.config, BackoffConfig::NoBackoff);
+        assert_eq!(checked.deprecations, vec![]);
+        assert_eq!(checked.unknown_keys.len(), 1);
+    }
+}
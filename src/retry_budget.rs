@@ -0,0 +1,296 @@
+use crate::*;
+use serde::Deserialize;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+/// Amount of slices the [RetryBudgetConfig::ttl] window is split into.
+const WINDOW_SLICES: usize = 10;
+
+#[derive(Debug, smart_default::SmartDefault, Clone, Copy, Deserialize, PartialEq)]
+/// Configuration for [RetryBudget], modelled after Finagle's retry budgets and gRPC retry throttling.
+///
+/// Every request deposits [RetryBudgetConfig::retry_ratio] tokens into the budget and every retry withdraws one token.
+/// Deposits and withdrawals expire after [RetryBudgetConfig::ttl]. A constant reserve of
+/// [RetryBudgetConfig::min_retries_per_second] retries per second allows retrying under low traffic.
+pub struct RetryBudgetConfig {
+    /// Ratio of retries to requests, e.g. `0.2` allows 1 retry per 5 requests.
+    ///
+    /// Defaults to `0.2` - see [defaults::retry_ratio].
+    #[serde(default = "defaults::retry_ratio")]
+    #[default(defaults::retry_ratio())]
+    pub retry_ratio: f32,
+
+    /// Minimum amount of retries per second that are allowed regardless of the [RetryBudgetConfig::retry_ratio].
+    ///
+    /// Defaults to `10` - see [defaults::min_retries_per_second].
+    #[serde(default = "defaults::min_retries_per_second")]
+    #[default(defaults::min_retries_per_second())]
+    pub min_retries_per_second: u32,
+
+    /// Time window after which deposits and withdrawals expire.
+    ///
+    /// Defaults to `10 seconds` - see [defaults::ttl].
+    #[serde(default = "defaults::ttl", deserialize_with = "deserialize_duration")]
+    #[default(defaults::ttl())]
+    pub ttl: Duration,
+}
+
+impl RetryBudgetConfig {
+    /// Checks that this [RetryBudgetConfig] describes a sensible budget.
+    pub fn validate(&self) -> Result<(), ValidationError> {
+        if !self.retry_ratio.is_finite() || self.retry_ratio < 0.0 {
+            return Err(ValidationError::new(
+                "retry_ratio",
+                format!("must be a finite number >= 0.0, got {}", self.retry_ratio),
+            ));
+        }
+
+        if self.ttl.is_zero() {
+            return Err(ValidationError::new("ttl", "must be greater than zero"));
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone)]
+/// Thread-safe token bucket limiting the amount of retries relative to the amount of requests - see
+/// [RetryBudgetConfig].
+///
+/// Cloning the budget is cheap and all clones share the same tokens. Retry loops consult the budget via
+/// [RetryBudget::backoff], which stops retrying once the budget is exhausted.
+pub struct RetryBudget {
+    inner: Arc<Inner>,
+}
+
+#[derive(Debug)]
+struct Inner {
+    config: RetryBudgetConfig,
+    clock: Box<dyn Clock>,
+    origin: Instant,
+    window: Mutex<[Slice; WINDOW_SLICES]>,
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+struct Slice {
+    index: u64,
+    tokens: f64,
+}
+
+impl RetryBudget {
+    /// Creates a new [RetryBudget] using the [SystemClock].
+    pub fn new(config: RetryBudgetConfig) -> RetryBudget {
+        RetryBudget::with_clock(config, SystemClock)
+    }
+
+    /// Creates a new [RetryBudget] using the given [Clock].
+    pub fn with_clock(config: RetryBudgetConfig, clock: impl Clock + 'static) -> RetryBudget {
+        RetryBudget {
+            inner: Arc::new(Inner {
+                config,
+                origin: clock.now(),
+                clock: Box::new(clock),
+                window: Mutex::new([Slice::default(); WINDOW_SLICES]),
+            }),
+        }
+    }
+
+    /// Returns the [RetryBudgetConfig] of this budget.
+    pub fn config(&self) -> RetryBudgetConfig {
+        self.inner.config
+    }
+
+    /// Records a request, depositing [RetryBudgetConfig::retry_ratio] tokens.
+    pub fn deposit(&self) {
+        let ratio = f64::from(self.inner.config.retry_ratio);
+        self.inner.update(|_| Some(ratio));
+    }
+
+    /// Withdraws a token for a retry, returning whether the retry is allowed.
+    pub fn try_withdraw(&self) -> bool {
+        self.inner
+            .update(|balance| (balance >= 1.0).then_some(-1.0))
+            .is_some()
+    }
+
+    /// Returns the amount of retries currently allowed.
+    pub fn balance(&self) -> usize {
+        self.inner.update(|_| Some(0.0)).unwrap_or(0.0) as usize
+    }
+
+    /// Records a request and wraps the [Backoff] built from `builder`, so it stops once this budget is exhausted.
+    pub fn backoff<B: backon::BackoffBuilder>(&self, builder: B) -> BudgetedBackoff<B::Backoff> {
+        self.deposit();
+
+        BudgetedBackoff {
+            budget: self.clone(),
+            backoff: builder.build(),
+            exhausted: false,
+        }
+    }
+}
+
+impl Inner {
+    /// Expires outdated slices and applies the token change returned by `f` for the current balance, if any.
+    ///
+    /// Returns the balance before the change, or `None` if `f` returned no change.
+    fn update(&self, f: impl FnOnce(f64) -> Option<f64>) -> Option<f64> {
+        let slice_len = self.config.ttl.as_secs_f64() / WINDOW_SLICES as f64;
+        let elapsed = self.clock.now().saturating_duration_since(self.origin);
+        let index = (elapsed.as_secs_f64() / slice_len) as u64;
+
+        let mut window = self.window.lock().unwrap_or_else(|e| e.into_inner());
+
+        for slice in window.iter_mut() {
+            if index.saturating_sub(slice.index) >= WINDOW_SLICES as u64 {
+                *slice = Slice::default();
+            }
+        }
+
+        let reserve = f64::from(self.config.min_retries_per_second) * self.config.ttl.as_secs_f64();
+        let balance = reserve + window.iter().map(|slice| slice.tokens).sum::<f64>();
+
+        let change = f(balance)?;
+
+        let slice = &mut window[index as usize % WINDOW_SLICES];
+        if slice.index != index {
+            *slice = Slice { index, tokens: 0.0 };
+        }
+        slice.tokens += change;
+
+        Some(balance)
+    }
+}
+
+#[derive(Debug)]
+/// [Backoff] wrapper that withdraws from a [RetryBudget] before every retry - see [RetryBudget::backoff].
+///
+/// Yields no more delays once the [RetryBudget] is exhausted.
+pub struct BudgetedBackoff<B> {
+    budget: RetryBudget,
+    backoff: B,
+    exhausted: bool,
+}
+
+impl<B: Iterator<Item = Duration>> Iterator for BudgetedBackoff<B> {
+    type Item = Duration;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.exhausted {
+            return None;
+        }
+
+        let delay = self.backoff.next()?;
+
+        if self.budget.try_withdraw() {
+            Some(delay)
+        } else {
+            self.exhausted = true;
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn budget(clock: &ManualClock) -> RetryBudget {
+        RetryBudget::with_clock(
+            RetryBudgetConfig {
+                retry_ratio: 0.5,
+                min_retries_per_second: 1,
+                ttl: Duration::from_secs(2),
+            },
+            clock.clone(),
+        )
+    }
+
+    #[test]
+    fn defaults() {
+        assert_eq!(
+            RetryBudgetConfig::default(),
+            RetryBudgetConfig {
+                retry_ratio: defaults::retry_ratio(),
+                min_retries_per_second: defaults::min_retries_per_second(),
+                ttl: defaults::ttl(),
+            }
+        );
+        assert_eq!(RetryBudgetConfig::default().validate(), Ok(()));
+    }
+
+    #[test]
+    fn validate() {
+        let negative_ratio = RetryBudgetConfig {
+            retry_ratio: -1.0,
+            ..Default::default()
+        };
+        let zero_ttl = RetryBudgetConfig {
+            ttl: Duration::ZERO,
+            ..Default::default()
+        };
+
+        assert_eq!(negative_ratio.validate().unwrap_err().field, "retry_ratio");
+        assert_eq!(zero_ttl.validate().unwrap_err().field, "ttl");
+    }
+
+    #[test]
+    fn allows_reserve_and_ratio_of_requests() {
+        let clock = ManualClock::new();
+        let budget = budget(&clock);
+
+        assert_eq!(budget.balance(), 2);
+
+        for _ in 0..4 {
+            budget.deposit();
+        }
+        assert_eq!(budget.balance(), 4);
+
+        for _ in 0..4 {
+            assert!(budget.try_withdraw());
+        }
+        assert!(!budget.try_withdraw());
+        assert_eq!(budget.balance(), 0);
+    }
+
+    #[test]
+    fn expires_tokens_after_ttl() {
+        let clock = ManualClock::new();
+        let budget = budget(&clock);
+
+        assert!(budget.try_withdraw());
+        assert!(budget.try_withdraw());
+        assert!(!budget.try_withdraw());
+
+        clock.advance(Duration::from_millis(1000));
+        for _ in 0..2 {
+            budget.deposit();
+        }
+        assert_eq!(budget.balance(), 1);
+
+        clock.advance(Duration::from_millis(1000));
+        assert_eq!(budget.balance(), 3);
+
+        clock.advance(Duration::from_millis(1000));
+        assert_eq!(budget.balance(), 2);
+    }
+
+    #[test]
+    fn budgeted_backoff_stops_when_exhausted() {
+        let clock = ManualClock::new();
+        let budget = budget(&clock);
+        let config = BackoffConfig::Constant(ConstantBackoffConfig {
+            delay: Duration::from_millis(100),
+            max_retries: 5,
+            jitter: Jitter::Disabled,
+        });
+
+        assert_eq!(budget.backoff(config).count(), 2);
+        assert_eq!(budget.backoff(config).count(), 1);
+
+        let mut backoff = budget.backoff(config);
+        assert_eq!(backoff.next(), None);
+        budget.deposit();
+        assert_eq!(backoff.next(), None);
+    }
+}
@@ -0,0 +1,346 @@
+use crate::*;
+use backon::{BackoffBuilder, BlockingSleeper, Sleeper};
+use serde::Serialize;
+use std::fmt::{Display, Formatter};
+use std::future::Future;
+use std::time::{Duration, Instant, SystemTime};
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+/// History of a retried operation - see [BackoffConfig::retry_with_report].
+pub struct RetryReport {
+    /// Summary of the [BackoffConfig] the operation was retried with - see its [Display] implementation.
+    pub policy: String,
+
+    /// Attempts of the operation, in order.
+    pub attempts: Vec<RetryAttempt>,
+
+    /// Reason why the attempts stopped.
+    pub termination: RetryTermination,
+}
+
+impl RetryReport {
+    /// Returns the total delay slept between the attempts.
+    pub fn total_delay(&self) -> Duration {
+        self.attempts
+            .iter()
+            .filter_map(|attempt| attempt.delay_after)
+            .fold(Duration::ZERO, Duration::saturating_add)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+/// Single attempt of a [RetryReport].
+pub struct RetryAttempt {
+    /// Number of the attempt, starting at `1`.
+    pub attempt: usize,
+
+    /// Wall-clock time at which the attempt started.
+    pub started_at: SystemTime,
+
+    /// Time the attempt took.
+    pub duration: Duration,
+
+    /// Display of the attempt's error, if it failed.
+    pub error: Option<String>,
+
+    /// Delay slept after the attempt, if it was retried.
+    pub delay_after: Option<Duration>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+/// Reason why the attempts of a [RetryReport] stopped.
+pub enum RetryTermination {
+    /// The last attempt succeeded.
+    Succeeded,
+
+    /// The last attempt failed and the maximum amount of retries was reached.
+    MaxRetries,
+
+    /// The last attempt failed and the next delay would have exceeded the maximum total delay.
+    MaxTotalDelay,
+}
+
+impl Display for RetryTermination {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RetryTermination::Succeeded => write!(f, "succeeded"),
+            RetryTermination::MaxRetries => write!(f, "max_retries"),
+            RetryTermination::MaxTotalDelay => write!(f, "max_total_delay"),
+        }
+    }
+}
+
+impl BackoffConfig {
+    /// Calls `operation` until it succeeds or this [BackoffConfig] stops retrying, sleeping with `sleeper` between
+    /// the attempts.
+    ///
+    /// Returns the last result along with a [RetryReport] of all attempts. `std::thread::sleep` can be used as the
+    /// `sleeper`.
+    pub fn retry_with_report<T, E, F>(
+        &self,
+        mut operation: F,
+        sleeper: impl BlockingSleeper,
+    ) -> (Result<T, E>, RetryReport)
+    where
+        E: Display,
+        F: FnMut() -> Result<T, E>,
+    {
+        let mut recorder = Recorder::new(self);
+
+        loop {
+            let (started_at, start) = (SystemTime::now(), Instant::now());
+            let result = operation();
+
+            match recorder.record(started_at, start.elapsed(), &result) {
+                Some(delay) => sleeper.sleep(delay),
+                None => return (result, recorder.report),
+            }
+        }
+    }
+
+    /// Async version of [BackoffConfig::retry_with_report], sleeping with the [Sleeper] between the attempts.
+    pub async fn retry_with_report_async<T, E, F, Fut>(
+        &self,
+        mut operation: F,
+        sleeper: impl Sleeper,
+    ) -> (Result<T, E>, RetryReport)
+    where
+        E: Display,
+        F: FnMut() -> Fut,
+        Fut: Future<Output = Result<T, E>>,
+    {
+        let mut recorder = Recorder::new(self);
+
+        loop {
+            let (started_at, start) = (SystemTime::now(), Instant::now());
+            let result = operation().await;
+
+            match recorder.record(started_at, start.elapsed(), &result) {
+                Some(delay) => {
+                    sleeper.sleep(delay).await;
+                }
+                None => return (result, recorder.report),
+            }
+        }
+    }
+}
+
+/// Builds a [RetryReport] while retrying.
+struct Recorder {
+    backoff: Backoff,
+    max_retries: usize,
+    report: RetryReport,
+}
+
+impl Recorder {
+    fn new(config: &BackoffConfig) -> Recorder {
+        Recorder {
+            backoff: config.build(),
+            max_retries: config.max_retries(),
+            report: RetryReport {
+                policy: config.to_string(),
+                attempts: Vec::new(),
+                termination: RetryTermination::Succeeded,
+            },
+        }
+    }
+
+    /// Records an attempt, returning the delay to sleep before the next attempt, if any.
+    fn record<T, E: Display>(
+        &mut self,
+        started_at: SystemTime,
+        duration: Duration,
+        result: &Result<T, E>,
+    ) -> Option<Duration> {
+        let delay_after = match result {
+            Ok(_) => None,
+            Err(_) => self.backoff.next(),
+        };
+
+        self.report.attempts.push(RetryAttempt {
+            attempt: self.report.attempts.len() + 1,
+            started_at,
+            duration,
+            error: result.as_ref().err().map(ToString::to_string),
+            delay_after,
+        });
+
+        if result.is_err() && delay_after.is_none() {
+            let retries = self.report.attempts.len() - 1;
+            self.report.termination = if retries < self.max_retries {
+                RetryTermination::MaxTotalDelay
+            } else {
+                RetryTermination::MaxRetries
+            };
+        }
+
+        delay_after
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::{Arc, Mutex};
+
+    fn config(max_total_delay: Duration) -> BackoffConfig {
+        BackoffConfig::Exponential(ExponentialBackoffConfig {
+            initial_delay: Duration::from_secs(1),
+            factor: 2_f32,
+            max_delay: Duration::from_secs(8),
+            max_retries: 3,
+            max_total_delay,
+            jitter: Jitter::Disabled,
+        })
+    }
+
+    fn delays(report: &RetryReport) -> Vec<Option<Duration>> {
+        report
+            .attempts
+            .iter()
+            .map(|attempt| attempt.delay_after)
+            .collect()
+    }
+
+    #[test]
+    fn reports_success_after_failures() {
+        let slept = Arc::new(Mutex::new(Vec::new()));
+        let sleeper = {
+            let slept = slept.clone();
+            move |delay| slept.lock().unwrap().push(delay)
+        };
+        let mut calls = 0;
+
+        let (result, report) = config(Duration::from_secs(1000)).retry_with_report(
+            || {
+                calls += 1;
+                if calls < 3 {
+                    Err(format!("failure {calls}"))
+                } else {
+                    Ok(calls)
+                }
+            },
+            sleeper,
+        );
+
+        assert_eq!(result, Ok(3));
+        assert_eq!(report.termination, RetryTermination::Succeeded);
+        assert_eq!(
+            report.policy,
+            "exponential 1s×2.0 up to 8s, 3 retries, ≤1000s total"
+        );
+        assert_eq!(
+            report
+                .attempts
+                .iter()
+                .map(|attempt| (attempt.attempt, attempt.error.clone()))
+                .collect::<Vec<_>>(),
+            vec![
+                (1, Some("failure 1".to_string())),
+                (2, Some("failure 2".to_string())),
+                (3, None)
+            ]
+        );
+        assert_eq!(
+            delays(&report),
+            vec![
+                Some(Duration::from_secs(1)),
+                Some(Duration::from_secs(2)),
+                None
+            ]
+        );
+        assert_eq!(
+            *slept.lock().unwrap(),
+            report
+                .attempts
+                .iter()
+                .filter_map(|a| a.delay_after)
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(report.total_delay(), Duration::from_secs(3));
+        assert!(report.attempts[0].started_at <= report.attempts[2].started_at);
+    }
+
+    #[test]
+    fn reports_max_retries() {
+        let (result, report) =
+            config(Duration::from_secs(1000)).retry_with_report(|| Err::<(), _>("failure"), |_| {});
+
+        assert_eq!(result, Err("failure"));
+        assert_eq!(report.attempts.len(), 4);
+        assert_eq!(report.termination, RetryTermination::MaxRetries);
+        assert_eq!(report.attempts[3].delay_after, None);
+    }
+
+    #[test]
+    fn reports_max_total_delay() {
+        let (_, report) = config(Duration::from_millis(3000 + 1))
+            .retry_with_report(|| Err::<(), _>("failure"), |_| {});
+
+        assert_eq!(report.attempts.len(), 3);
+        assert_eq!(report.termination, RetryTermination::MaxTotalDelay);
+        assert_eq!(report.termination.to_string(), "max_total_delay");
+    }
+
+    #[test]
+    fn reports_no_backoff() {
+        let (_, report) =
+            BackoffConfig::NoBackoff.retry_with_report(|| Err::<(), _>("failure"), |_| {});
+
+        assert_eq!(report.attempts.len(), 1);
+        assert_eq!(report.termination, RetryTermination::MaxRetries);
+    }
+
+    #[tokio::test]
+    async fn reports_async_retries() {
+        let (result, report) = config(Duration::from_secs(1000))
+            .retry_with_report_async(
+                || async { Err::<(), _>("failure") },
+                |_| std::future::ready(()),
+            )
+            .await;
+
+        assert_eq!(result, Err("failure"));
+        assert_eq!(report.termination, RetryTermination::MaxRetries);
+        assert_eq!(
+            delays(&report),
+            vec![
+                Some(Duration::from_secs(1)),
+                Some(Duration::from_secs(2)),
+                Some(Duration::from_secs(4)),
+                None
+            ]
+        );
+    }
+
+    #[test]
+    fn serializes_report() {
+        let report = RetryReport {
+            policy: BackoffConfig::NoBackoff.to_string(),
+            attempts: vec![RetryAttempt {
+                attempt: 1,
+                started_at: SystemTime::UNIX_EPOCH,
+                duration: Duration::from_millis(5),
+                error: Some("failure".to_string()),
+                delay_after: None,
+            }],
+            termination: RetryTermination::MaxRetries,
+        };
+
+        assert_eq!(
+            serde_json::to_value(&report).unwrap(),
+            serde_json::json!({
+                "policy": "no backoff",
+                "attempts": [{
+                    "attempt": 1,
+                    "started_at": { "secs_since_epoch": 0, "nanos_since_epoch": 0 },
+                    "duration": { "secs": 0, "nanos": 5_000_000 },
+                    "error": "failure",
+                    "delay_after": null,
+                }],
+                "termination": "max_retries",
+            })
+        );
+    }
+}
@@ -0,0 +1,482 @@
+use crate::*;
+use backon::BackoffBuilder;
+use std::fmt::{Display, Formatter};
+use std::time::Duration;
+
+#[derive(Debug, Clone, PartialEq)]
+/// Simulated retry schedule of a [BackoffConfig] - see [BackoffConfig::simulate].
+pub struct Schedule {
+    /// Simulated retries, in order.
+    pub attempts: Vec<ScheduledAttempt>,
+
+    /// Reason why the retries stopped.
+    pub termination: Termination,
+}
+
+impl Schedule {
+    /// Maximum amount of retries of a simulated [Schedule]. Longer schedules are cut off with
+    /// [Termination::Truncated].
+    pub const MAX_ATTEMPTS: usize = 10_000;
+
+    /// Returns the total delay of all retries.
+    pub fn total_delay(&self) -> Duration {
+        self.attempts
+            .last()
+            .map(|attempt| attempt.elapsed)
+            .unwrap_or_default()
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// Single retry of a [Schedule].
+pub struct ScheduledAttempt {
+    /// Number of the retry, starting at `1`.
+    pub attempt: usize,
+
+    /// Delay before the retry.
+    pub delay: Duration,
+
+    /// Cumulative delay up to and including this retry.
+    pub elapsed: Duration,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// Reason why a [Schedule] stopped.
+pub enum Termination {
+    /// The maximum amount of retries was reached.
+    MaxRetries,
+
+    /// The next delay would have exceeded the maximum total delay.
+    MaxTotalDelay,
+
+    /// The simulation stopped after [Schedule::MAX_ATTEMPTS] retries, although more retries would follow.
+    Truncated,
+}
+
+impl Display for Termination {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Termination::MaxRetries => write!(f, "max_retries"),
+            Termination::MaxTotalDelay => write!(f, "max_total_delay"),
+            Termination::Truncated => write!(f, "truncated"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// Distribution of total delays over many simulated [Schedule]s - see [BackoffConfig::sample].
+pub struct ScheduleStats {
+    /// Amount of simulated schedules.
+    pub runs: usize,
+
+    /// Median total delay.
+    pub p50: Duration,
+
+    /// 95th percentile total delay.
+    pub p95: Duration,
+
+    /// Maximum total delay.
+    pub max: Duration,
+}
+
+impl BackoffConfig {
+    /// Simulates the retry schedule of this [BackoffConfig], using `seed` as the jitter seed.
+    ///
+    /// The same `seed` always produces the same [Schedule], regardless of [BackoffConfig]'s own jitter seed. At most
+    /// [Schedule::MAX_ATTEMPTS] retries are simulated.
+    pub fn simulate(&self, seed: u64) -> Schedule {
+        let mut backoff = self.with_jitter_seed(seed).build();
+        let mut elapsed = Duration::ZERO;
+
+        let attempts = backoff
+            .by_ref()
+            .take(Schedule::MAX_ATTEMPTS)
+            .enumerate()
+            .map(|(index, delay)| {
+                elapsed = elapsed.saturating_add(delay);
+                ScheduledAttempt {
+                    attempt: index + 1,
+                    delay,
+                    elapsed,
+                }
+            })
+            .collect::<Vec<_>>();
+
+        let termination = if attempts.len() == Schedule::MAX_ATTEMPTS && backoff.next().is_some() {
+            Termination::Truncated
+        } else if attempts.len() < self.max_retries() {
+            Termination::MaxTotalDelay
+        } else {
+            Termination::MaxRetries
+        };
+
+        Schedule {
+            attempts,
+            termination,
+        }
+    }
+
+    /// Simulates `runs` retry schedules with jitter seeds derived from `seed`, reporting the distribution of their
+    /// total delays.
+    ///
+    /// Like [BackoffConfig::simulate], every schedule is cut off after [Schedule::MAX_ATTEMPTS] retries.
+    pub fn sample(&self, runs: usize, seed: u64) -> ScheduleStats {
+        let mut rng = fastrand::Rng::with_seed(seed);
+
+        let mut totals = (0..runs)
+            .map(|_| self.simulate(rng.u64(..)).total_delay())
+            .collect::<Vec<_>>();
+        totals.sort_unstable();
+
+        let percentile = |p: f64| {
+            let rank = (p * totals.len() as f64).ceil() as usize;
+            totals
+                .get(rank.saturating_sub(1))
+                .copied()
+                .unwrap_or_default()
+        };
+
+        ScheduleStats {
+            runs,
+            p50: percentile(0.5),
+            p95: percentile(0.95),
+            max: totals.last().copied().unwrap_or_default(),
+        }
+    }
+
+    /// Returns the delay before retry `n` (starting at `0`) of a [Backoff] built with `seed` as its jitter seed, or
+    /// `None` if the [Backoff] stops before that retry. Jittered delays take time linear in `n`.
+    ///
+    /// The result is exactly the `n`th delay of iterating such a [Backoff], including the maximum delay and the
+    /// maximum total delay. Delays without jitter are computed directly, walking at most the growth phase up to the
+    /// maximum delay. Jittered delays replay the `n` previous jitter draws, and jittered delays that may exceed the
+    /// maximum total delay replay all previous delays.
+    pub fn delay_for_attempt(&self, n: usize, seed: u64) -> Option<Duration> {
+        let bounds = self.schedule_bounds();
+        if n >= bounds.max_retries {
+            return None;
+        }
+
+        let base = self.base_delay(n);
+        if !self.jitter().is_enabled() {
+            return Some(base);
+        }
+
+        // Only strategies with a maximum total delay may stop before their maximum amount of retries.
+        if n >= bounds.min_retries {
+            return self.with_jitter_seed(seed).build().nth(n);
+        }
+
+        // Every retry draws once from the jitter generator.
+        let mut rng = crate::backoff::rng(Some(seed));
+
+        let jitter = match self {
+            BackoffConfig::RandomizedExponential(config) => {
+                let factor = f64::from(config.randomization_factor);
+                if factor <= 0.0 {
+                    return Some(base);
+                }
+
+                for _ in 0..n {
+                    rng.f64();
+                }
+                let random = 1.0 - factor + 2.0 * factor * rng.f64();
+                return Some(crate::backoff::scale(base, random));
+            }
+            BackoffConfig::Constant(_)
+            | BackoffConfig::Exponential(_)
+            | BackoffConfig::Adaptive(_) => base,
+            BackoffConfig::Fibonacci(config) => config.initial_delay,
+            BackoffConfig::Polynomial(config) => crate::backoff::polynomial_padding(config, n),
+            BackoffConfig::Random(config) => config.max_delay.saturating_sub(config.min_delay),
+            BackoffConfig::NoBackoff => return None,
+        };
+
+        for _ in 0..n {
+            rng.f32();
+        }
+        let delay = crate::backoff::jitter(base, jitter, &mut rng);
+
+        Some(match self {
+            BackoffConfig::Polynomial(config) => delay.min(config.max_delay),
+            _ => delay,
+        })
+    }
+
+    /// Returns the jitter of this [BackoffConfig].
+    pub(crate) fn jitter(&self) -> Jitter {
+        match self {
+            BackoffConfig::Constant(config) => config.jitter,
+            BackoffConfig::Exponential(config) => config.jitter,
+            BackoffConfig::Fibonacci(config) => config.jitter,
+            BackoffConfig::Adaptive(config) => config.jitter,
+            BackoffConfig::RandomizedExponential(config) => config.jitter,
+            BackoffConfig::Polynomial(config) => config.jitter,
+            BackoffConfig::Random(config) => config.seed.map_or(Jitter::Random, Jitter::Seeded),
+            BackoffConfig::NoBackoff => Jitter::Disabled,
+        }
+    }
+
+    /// Returns the maximum amount of retries of this [BackoffConfig].
+    pub(crate) fn max_retries(&self) -> usize {
+        match self {
+            BackoffConfig::Constant(config) => config.max_retries,
+            BackoffConfig::Exponential(config) => config.max_retries,
+            BackoffConfig::Fibonacci(config) => config.max_retries,
+            BackoffConfig::Adaptive(config) => config.max_retries,
+            BackoffConfig::RandomizedExponential(config) => config.max_retries,
+            BackoffConfig::Polynomial(config) => config.max_retries,
+            BackoffConfig::Random(config) => config.max_retries,
+            BackoffConfig::NoBackoff => 0,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn millis(schedule: &Schedule) -> Vec<(u128, u128)> {
+        schedule
+            .attempts
+            .iter()
+            .map(|attempt| (attempt.delay.as_millis(), attempt.elapsed.as_millis()))
+            .collect()
+    }
+
+    #[test]
+    fn simulates_max_retries() {
+        let config = BackoffConfig::Exponential(ExponentialBackoffConfig {
+            initial_delay: Duration::from_millis(100),
+            factor: 2_f32,
+            max_delay: Duration::from_millis(800),
+            max_retries: 5,
+            max_total_delay: Duration::from_secs(1000),
+            jitter: Jitter::Disabled,
+        });
+
+        let schedule = config.simulate(0);
+
+        assert_eq!(
+            millis(&schedule),
+            vec![(100, 100), (200, 300), (400, 700), (800, 1500), (800, 2300)]
+        );
+        assert_eq!(schedule.attempts[4].attempt, 5);
+        assert_eq!(schedule.termination, Termination::MaxRetries);
+        assert_eq!(schedule.total_delay(), schedule.attempts[4].elapsed);
+    }
+
+    #[test]
+    fn simulates_max_total_delay() {
+        let config = BackoffConfig::Exponential(ExponentialBackoffConfig {
+            initial_delay: Duration::from_millis(100),
+            factor: 2_f32,
+            max_delay: Duration::from_millis(800),
+            max_retries: 5,
+            max_total_delay: Duration::from_millis(1500 + 1),
+            jitter: Jitter::Disabled,
+        });
+
+        let schedule = config.simulate(0);
+
+        assert_eq!(
+            millis(&schedule),
+            vec![(100, 100), (200, 300), (400, 700), (800, 1500)]
+        );
+        assert_eq!(schedule.termination, Termination::MaxTotalDelay);
+        assert_eq!(schedule.termination.to_string(), "max_total_delay");
+    }
+
+    #[test]
+    fn simulates_with_given_seed() {
+        let config = BackoffConfig::Fibonacci(FibonacciBackoffConfig {
+            initial_delay: Duration::from_millis(100),
+            max_delay: Duration::from_millis(800),
+            max_retries: 5,
+            jitter: Jitter::Seeded(1337),
+        });
+
+        assert_eq!(
+            millis(&config.simulate(0)),
+            vec![(155, 155), (109, 264), (259, 524), (315, 839), (537, 1377)]
+        );
+        assert_eq!(config.simulate(42), config.simulate(42));
+    }
+
+    #[test]
+    fn truncates_unlimited_schedule() {
+        let config = BackoffConfig::Exponential(
+            ExponentialBackoffConfig::new()
+                .max_retries(usize::MAX)
+                .max_total_delay(Duration::MAX),
+        );
+
+        let schedule = config.simulate(0);
+
+        assert_eq!(schedule.attempts.len(), Schedule::MAX_ATTEMPTS);
+        assert_eq!(schedule.termination, Termination::Truncated);
+        assert_eq!(config.sample(3, 0).runs, 3);
+
+        let config = BackoffConfig::Constant(
+            ConstantBackoffConfig::new().max_retries(Schedule::MAX_ATTEMPTS),
+        );
+        assert_eq!(config.simulate(0).termination, Termination::MaxRetries);
+    }
+
+    #[test]
+    fn simulates_no_backoff() {
+        let schedule = BackoffConfig::NoBackoff.simulate(0);
+
+        assert!(schedule.attempts.is_empty());
+        assert_eq!(schedule.termination, Termination::MaxRetries);
+        assert_eq!(schedule.total_delay(), Duration::ZERO);
+    }
+
+    #[test]
+    fn delay_for_attempt_matches_iteration() {
+        let configs = [
+            BackoffConfig::Constant(ConstantBackoffConfig::default()),
+            BackoffConfig::Exponential(ExponentialBackoffConfig {
+                max_retries: 20,
+                max_total_delay: Duration::from_secs(20),
+                ..Default::default()
+            }),
+            BackoffConfig::Fibonacci(FibonacciBackoffConfig {
+                max_retries: 20,
+                max_delay: Duration::from_secs(5),
+                ..Default::default()
+            }),
+            BackoffConfig::Adaptive(AdaptiveBackoffConfig::default()),
+            BackoffConfig::RandomizedExponential(RandomizedExponentialBackoffConfig {
+                max_elapsed_time: Duration::from_secs(20),
+                ..Default::default()
+            }),
+            BackoffConfig::Polynomial(PolynomialBackoffConfig::default()),
+            BackoffConfig::Random(RandomBackoffConfig::default()),
+            BackoffConfig::NoBackoff,
+            BackoffConfig::Exponential(
+                ExponentialBackoffConfig::new()
+                    .max_retries(20)
+                    .max_total_delay(Duration::from_secs(20))
+                    .jitter(Jitter::Disabled),
+            ),
+            BackoffConfig::Fibonacci(
+                FibonacciBackoffConfig::new()
+                    .max_retries(20)
+                    .max_delay(Duration::from_secs(5))
+                    .jitter(Jitter::Disabled),
+            ),
+            BackoffConfig::Adaptive(AdaptiveBackoffConfig {
+                jitter: Jitter::Disabled,
+                ..Default::default()
+            }),
+            BackoffConfig::RandomizedExponential(
+                RandomizedExponentialBackoffConfig::new()
+                    .max_elapsed_time(Duration::from_secs(20))
+                    .jitter(Jitter::Disabled),
+            ),
+            BackoffConfig::Polynomial(
+                PolynomialBackoffConfig::new()
+                    .max_delay(Duration::from_secs(100))
+                    .max_retries(10)
+                    .jitter(Jitter::Disabled),
+            ),
+        ];
+
+        for config in configs {
+            for seed in 0..20 {
+                let delays = config.with_jitter_seed(seed).build().collect::<Vec<_>>();
+
+                for n in 0..25 {
+                    assert_eq!(
+                        config.delay_for_attempt(n, seed),
+                        delays.get(n).copied(),
+                        "{config:?} with seed {seed}, attempt {n}"
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn delay_for_attempt_with_many_retries() {
+        let config = BackoffConfig::Exponential(
+            ExponentialBackoffConfig::new()
+                .max_retries(usize::MAX)
+                .max_total_delay(Duration::MAX)
+                .jitter(Jitter::Disabled),
+        );
+        assert_eq!(
+            config.delay_for_attempt(1 << 40, 0),
+            Some(ExponentialBackoffConfig::new().max_delay)
+        );
+
+        let config = BackoffConfig::Polynomial(
+            PolynomialBackoffConfig::new()
+                .exponent(1_f32)
+                .max_delay(Duration::MAX)
+                .max_retries(usize::MAX)
+                .jitter(Jitter::Disabled),
+        );
+        assert_eq!(
+            config.delay_for_attempt(1 << 40, 0),
+            Some(Duration::from_secs(15 + (1 << 40)))
+        );
+
+        let config = BackoffConfig::Fibonacci(
+            FibonacciBackoffConfig::new()
+                .max_retries(usize::MAX)
+                .jitter(Jitter::Random),
+        );
+        let n = 100_000;
+        assert_eq!(
+            config.delay_for_attempt(n, 7),
+            config.with_jitter_seed(7).build().nth(n)
+        );
+    }
+
+    #[test]
+    fn delay_for_attempt_beyond_max_retries() {
+        let config = BackoffConfig::Constant(ConstantBackoffConfig {
+            max_retries: 3,
+            ..Default::default()
+        });
+
+        assert!(config.delay_for_attempt(2, 0).is_some());
+        assert_eq!(config.delay_for_attempt(3, 0), None);
+        assert_eq!(config.delay_for_attempt(usize::MAX, 0), None);
+    }
+
+    #[test]
+    fn samples_total_delays() {
+        let config = BackoffConfig::Constant(ConstantBackoffConfig {
+            delay: Duration::from_secs(1),
+            max_retries: 4,
+            jitter: Jitter::Random,
+        });
+
+        let stats = config.sample(1000, 0);
+
+        assert_eq!(stats.runs, 1000);
+        assert!(stats.p50 >= Duration::from_secs(4));
+        assert!(stats.p50 <= stats.p95);
+        assert!(stats.p95 <= stats.max);
+        assert!(stats.max < Duration::from_secs(8));
+        assert_eq!(stats, config.sample(1000, 0));
+    }
+
+    #[test]
+    fn samples_without_jitter() {
+        let config = BackoffConfig::Constant(ConstantBackoffConfig {
+            delay: Duration::from_secs(1),
+            max_retries: 4,
+            jitter: Jitter::Disabled,
+        });
+
+        let stats = config.sample(10, 0);
+
+        assert_eq!(stats.p50, Duration::from_secs(4));
+        assert_eq!(stats.p95, Duration::from_secs(4));
+        assert_eq!(stats.max, Duration::from_secs(4));
+    }
+}
@@ -0,0 +1,180 @@
+use crate::*;
+use arc_swap::ArcSwap;
+use std::sync::mpsc::{Receiver, Sender, channel};
+use std::sync::{Arc, Mutex};
+
+#[derive(Debug, Clone)]
+/// Hot-reloadable, shared handle to a [BackoffConfig].
+///
+/// Cloning the handle is cheap and all clones observe the same [BackoffConfig]. Reads are lock-free, so the handle
+/// can be used directly as a [backon::BackoffBuilder]: every retry loop builds its [Backoff] from the latest config.
+///
+/// The config is replaced via [SharedBackoffConfig::store], e.g. from a config-reload task. Subscribers registered
+/// via [SharedBackoffConfig::subscribe] are notified about every change.
+pub struct SharedBackoffConfig {
+    inner: Arc<Inner>,
+}
+
+#[derive(Debug)]
+struct Inner {
+    current: ArcSwap<BackoffConfig>,
+    subscribers: Mutex<Vec<Sender<BackoffConfig>>>,
+}
+
+impl SharedBackoffConfig {
+    /// Creates a new [SharedBackoffConfig] holding the given [BackoffConfig].
+    pub fn new(config: BackoffConfig) -> SharedBackoffConfig {
+        SharedBackoffConfig {
+            inner: Arc::new(Inner {
+                current: ArcSwap::from_pointee(config),
+                subscribers: Mutex::new(Vec::new()),
+            }),
+        }
+    }
+
+    /// Returns the current [BackoffConfig].
+    pub fn load(&self) -> BackoffConfig {
+        **self.inner.current.load()
+    }
+
+    /// Replaces the current [BackoffConfig], returning the previous one.
+    ///
+    /// Subscribers are notified only if the new config differs from the previous one.
+    pub fn store(&self, config: BackoffConfig) -> BackoffConfig {
+        // Swapping under the lock makes subscribers receive the configs in the order they were stored, ending with
+        // the current one. Reads stay lock-free.
+        let mut subscribers = self.lock_subscribers();
+        let previous = *self.inner.current.swap(Arc::new(config));
+
+        if previous != config {
+            subscribers.retain(|subscriber| subscriber.send(config).is_ok());
+        }
+
+        previous
+    }
+
+    /// Subscribes to changes of the [BackoffConfig].
+    ///
+    /// The returned [Receiver] yields every [BackoffConfig] passed to [SharedBackoffConfig::store] that differs from
+    /// the one it replaced, in the order they were stored. Dropping the [Receiver] unsubscribes.
+    pub fn subscribe(&self) -> Receiver<BackoffConfig> {
+        let (sender, receiver) = channel();
+        self.lock_subscribers().push(sender);
+        receiver
+    }
+
+    fn lock_subscribers(&self) -> std::sync::MutexGuard<'_, Vec<Sender<BackoffConfig>>> {
+        self.inner
+            .subscribers
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+impl From<BackoffConfig> for SharedBackoffConfig {
+    fn from(config: BackoffConfig) -> SharedBackoffConfig {
+        SharedBackoffConfig::new(config)
+    }
+}
+
+impl backon::BackoffBuilder for SharedBackoffConfig {
+    type Backoff = Backoff;
+
+    fn build(self) -> Backoff {
+        self.load().build()
+    }
+}
+
+impl backon::BackoffBuilder for &SharedBackoffConfig {
+    type Backoff = Backoff;
+
+    fn build(self) -> Backoff {
+        self.load().build()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use backon::BackoffBuilder;
+    use std::time::Duration;
+
+    fn constant(delay_millis: u64) -> BackoffConfig {
+        BackoffConfig::Constant(ConstantBackoffConfig {
+            delay: Duration::from_millis(delay_millis),
+            max_retries: 2,
+            jitter: Jitter::Disabled,
+        })
+    }
+
+    #[test]
+    fn clones_observe_stored_config() {
+        let shared = SharedBackoffConfig::new(constant(100));
+        let clone = shared.clone();
+
+        let previous = shared.store(constant(200));
+
+        assert_eq!(previous, constant(100));
+        assert_eq!(clone.load(), constant(200));
+    }
+
+    #[test]
+    fn builds_backoff_from_latest_config() {
+        let shared = SharedBackoffConfig::from(constant(100));
+
+        assert_eq!(
+            (&shared).build().map(|d| d.as_millis()).collect::<Vec<_>>(),
+            vec![100, 100]
+        );
+
+        shared.store(BackoffConfig::NoBackoff);
+
+        assert!(matches!(shared.build(), Backoff::NoBackoff));
+    }
+
+    #[test]
+    fn notifies_subscribers_about_changes() {
+        let shared = SharedBackoffConfig::new(constant(100));
+        let subscriber = shared.subscribe();
+
+        shared.store(constant(100));
+        shared.store(constant(200));
+        shared.store(BackoffConfig::NoBackoff);
+
+        assert_eq!(
+            subscriber.try_iter().collect::<Vec<_>>(),
+            vec![constant(200), BackoffConfig::NoBackoff]
+        );
+    }
+
+    #[test]
+    fn notifies_concurrent_stores_in_order() {
+        let shared = SharedBackoffConfig::new(constant(0));
+        let subscriber = shared.subscribe();
+
+        std::thread::scope(|scope| {
+            for thread in 0..8 {
+                let shared = &shared;
+                scope.spawn(move || {
+                    for delay in 1..=100 {
+                        shared.store(constant(thread * 1000 + delay));
+                    }
+                });
+            }
+        });
+
+        assert_eq!(subscriber.try_iter().last(), Some(shared.load()));
+    }
+
+    #[test]
+    fn drops_unsubscribed_receivers() {
+        let shared = SharedBackoffConfig::new(constant(100));
+        drop(shared.subscribe());
+        let subscriber = shared.subscribe();
+
+        shared.store(constant(200));
+
+        assert_eq!(shared.lock_subscribers().len(), 1);
+        assert_eq!(subscriber.try_recv(), Ok(constant(200)));
+    }
+}
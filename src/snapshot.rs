@@ -0,0 +1,182 @@
+use crate::*;
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+/// Runtime state of a [Backoff], e.g. to persist retries across process restarts - see [Backoff::snapshot].
+///
+/// A [Backoff] resumed via [BackoffConfig::resume] yields exactly the same delays as the snapshotted one would have,
+/// as long as it is resumed with the same [BackoffConfig].
+pub struct BackoffSnapshot {
+    /// Amount of delays requested so far.
+    pub attempts: usize,
+
+    /// Base delay (without jitter) of the latest retry, or the current base delay of [Backoff::Adaptive]. The interval
+    /// (before randomization) of [Backoff::RandomizedExponential].
+    ///
+    /// Unused by [Backoff::Constant], [Backoff::Polynomial], [Backoff::Random] and [Backoff::NoBackoff].
+    pub current_delay: Option<Duration>,
+
+    /// Base delay of the retry before the latest one.
+    ///
+    /// Only used by [Backoff::Fibonacci].
+    pub previous_delay: Option<Duration>,
+
+    /// Sum of all delays yielded so far, including jitter.
+    pub cumulative_delay: Duration,
+
+    /// State of the jitter random number generator.
+    pub rng_state: u64,
+}
+
+impl Backoff {
+    /// Captures the runtime state of this [Backoff] - see [BackoffSnapshot].
+    pub fn snapshot(&self) -> BackoffSnapshot {
+        match self {
+            Backoff::Constant(backoff) => backoff.snapshot(),
+            Backoff::Exponential(backoff) => backoff.snapshot(),
+            Backoff::Fibonacci(backoff) => backoff.snapshot(),
+            Backoff::Adaptive(backoff) => backoff.snapshot(),
+            Backoff::RandomizedExponential(backoff) => backoff.snapshot(),
+            Backoff::Polynomial(backoff) => backoff.snapshot(),
+            Backoff::Random(backoff) => backoff.snapshot(),
+            Backoff::NoBackoff => BackoffSnapshot {
+                attempts: 0,
+                current_delay: None,
+                previous_delay: None,
+                cumulative_delay: Duration::ZERO,
+                rng_state: 0,
+            },
+        }
+    }
+}
+
+impl BackoffConfig {
+    /// Builds a [Backoff] that continues where the snapshotted [Backoff] of this [BackoffConfig] stopped.
+    ///
+    /// [Backoff::Adaptive] resumes with a new [AdaptiveState] starting at the snapshotted base delay.
+    pub fn resume(&self, snapshot: &BackoffSnapshot) -> Backoff {
+        match *self {
+            BackoffConfig::Constant(config) => {
+                Backoff::Constant(ConstantBackoff::resume(config, snapshot))
+            }
+            BackoffConfig::Exponential(config) => {
+                Backoff::Exponential(ExponentialBackoff::resume(config, snapshot))
+            }
+            BackoffConfig::Fibonacci(config) => {
+                Backoff::Fibonacci(FibonacciBackoff::resume(config, snapshot))
+            }
+            BackoffConfig::Adaptive(config) => {
+                Backoff::Adaptive(AdaptiveBackoff::resume(config, snapshot))
+            }
+            BackoffConfig::RandomizedExponential(config) => Backoff::RandomizedExponential(
+                RandomizedExponentialBackoff::resume(config, snapshot),
+            ),
+            BackoffConfig::Polynomial(config) => {
+                Backoff::Polynomial(PolynomialBackoff::resume(config, snapshot))
+            }
+            BackoffConfig::Random(config) => {
+                Backoff::Random(RandomBackoff::resume(config, snapshot))
+            }
+            BackoffConfig::NoBackoff => Backoff::NoBackoff,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use backon::BackoffBuilder;
+
+    fn configs() -> Vec<BackoffConfig> {
+        vec![
+            BackoffConfig::Constant(ConstantBackoffConfig {
+                max_retries: 10,
+                jitter: Jitter::Seeded(1),
+                ..Default::default()
+            }),
+            BackoffConfig::Exponential(ExponentialBackoffConfig {
+                initial_delay: Duration::from_millis(100),
+                max_retries: 10,
+                max_total_delay: Duration::from_secs(20),
+                jitter: Jitter::Seeded(2),
+                ..Default::default()
+            }),
+            BackoffConfig::Fibonacci(FibonacciBackoffConfig {
+                max_delay: Duration::from_secs(3),
+                max_retries: 10,
+                jitter: Jitter::Seeded(3),
+                ..Default::default()
+            }),
+            BackoffConfig::Adaptive(AdaptiveBackoffConfig {
+                max_retries: 10,
+                jitter: Jitter::Seeded(4),
+                ..Default::default()
+            }),
+            BackoffConfig::RandomizedExponential(RandomizedExponentialBackoffConfig {
+                initial_interval: Duration::from_millis(100),
+                max_interval: Duration::from_secs(2),
+                max_elapsed_time: Duration::from_secs(10),
+                jitter: Jitter::Seeded(5),
+                ..Default::default()
+            }),
+            BackoffConfig::Polynomial(PolynomialBackoffConfig {
+                max_retries: 10,
+                jitter: Jitter::Seeded(6),
+                ..Default::default()
+            }),
+            BackoffConfig::Random(RandomBackoffConfig {
+                max_retries: 10,
+                seed: Some(7),
+                ..Default::default()
+            }),
+            BackoffConfig::NoBackoff,
+        ]
+    }
+
+    #[test]
+    fn resumes_with_same_delays() {
+        for config in configs() {
+            let expected = config.build().collect::<Vec<_>>();
+
+            for consumed in 0..=expected.len() {
+                let mut backoff = config.build();
+                backoff.by_ref().take(consumed).for_each(drop);
+
+                let snapshot = backoff.snapshot();
+                assert_eq!(snapshot.attempts, consumed, "{config:?}");
+                assert_eq!(
+                    snapshot.cumulative_delay,
+                    expected[..consumed].iter().sum::<Duration>()
+                );
+
+                assert_eq!(
+                    config.resume(&snapshot).collect::<Vec<_>>(),
+                    expected[consumed..],
+                    "{config:?} resumed after {consumed} delays"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn resumes_from_serialized_snapshot() {
+        let config = BackoffConfig::Exponential(ExponentialBackoffConfig {
+            jitter: Jitter::Seeded(42),
+            ..Default::default()
+        });
+
+        let mut backoff = config.build();
+        backoff.next();
+        backoff.next();
+
+        let json = serde_json::to_string(&backoff.snapshot()).unwrap();
+        let snapshot = serde_json::from_str::<BackoffSnapshot>(&json).unwrap();
+
+        assert_eq!(snapshot, backoff.snapshot());
+        assert_eq!(
+            config.resume(&snapshot).collect::<Vec<_>>(),
+            backoff.collect::<Vec<_>>()
+        );
+    }
+}
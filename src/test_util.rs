@@ -0,0 +1,229 @@
+use crate::*;
+use std::future::{Ready, ready};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+#[derive(Debug, Clone, Default)]
+/// [backon::Sleeper] and [backon::BlockingSleeper] that records the requested delays instead of sleeping.
+///
+/// Cloning the sleeper is cheap and all clones share the same recorded delays, so a clone can be passed to backon's
+/// `sleep` while the original is used for assertions:
+///
+/// ```
+/// use backoff_config::*;
+/// use backon::BlockingRetryable;
+/// use std::time::Duration;
+///
+/// let config = BackoffConfig::Constant(
+///     ConstantBackoffConfig::new()
+///         .delay(Duration::from_secs(1))
+///         .max_retries(3),
+/// )
+/// .with_jitter_seed(42);
+///
+/// let sleeper = RecordingSleeper::new();
+/// let result = (|| Err::<(), _>("failure"))
+///     .retry(config)
+///     .sleep(sleeper.clone())
+///     .call();
+///
+/// assert!(result.is_err());
+/// sleeper.assert_schedule(&config);
+/// ```
+pub struct RecordingSleeper {
+    delays: Arc<Mutex<Vec<Duration>>>,
+}
+
+impl RecordingSleeper {
+    /// Creates a new [RecordingSleeper] without recorded delays.
+    pub fn new() -> RecordingSleeper {
+        RecordingSleeper::default()
+    }
+
+    /// Returns the recorded delays, in order.
+    pub fn delays(&self) -> Vec<Duration> {
+        self.lock().clone()
+    }
+
+    /// Returns the sum of the recorded delays.
+    pub fn total_delay(&self) -> Duration {
+        self.lock()
+            .iter()
+            .fold(Duration::ZERO, |total, delay| total.saturating_add(*delay))
+    }
+
+    /// Forgets the recorded delays.
+    pub fn clear(&self) {
+        self.lock().clear();
+    }
+
+    /// Asserts that the recorded delays are exactly the delays of a [Backoff] built from `config`.
+    ///
+    /// If `config` has jitter enabled, it must have a fixed seed - see [BackoffConfig::with_jitter_seed].
+    #[track_caller]
+    pub fn assert_schedule(&self, config: &BackoffConfig) {
+        let recorded = self.delays();
+        // One more delay than recorded is enough to tell the schedules apart, even for unlimited retries.
+        let expected = backon::BackoffBuilder::build(*config)
+            .take(recorded.len() + 1)
+            .collect::<Vec<_>>();
+
+        assert!(
+            recorded == expected,
+            "recorded delays differ from the schedule of {config:?}\n  recorded: {recorded:?}\n  expected: {expected:?}"
+        );
+    }
+
+    /// Asserts that every recorded delay is within the [BackoffConfig::delay_bounds] of `config`, and that no more
+    /// delays were recorded than `config` allows.
+    ///
+    /// Unlike [RecordingSleeper::assert_schedule], this does not require a fixed jitter seed or every retry to fail.
+    #[track_caller]
+    pub fn assert_within_bounds(&self, config: &BackoffConfig) {
+        let recorded = self.delays();
+        let bounds = config
+            .delay_bounds()
+            .take(recorded.len() + 1)
+            .collect::<Vec<_>>();
+
+        assert!(
+            recorded.len() <= bounds.len(),
+            "recorded {} delays, but {config:?} allows at most {}\n  recorded: {recorded:?}",
+            recorded.len(),
+            bounds.len()
+        );
+
+        for (index, (delay, bounds)) in recorded.iter().zip(&bounds).enumerate() {
+            assert!(
+                (bounds.min..=bounds.max).contains(delay),
+                "recorded delay #{} of {delay:?} is outside of {:?}..={:?}\n  recorded: {recorded:?}",
+                index + 1,
+                bounds.min,
+                bounds.max
+            );
+        }
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, Vec<Duration>> {
+        self.delays.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+impl backon::Sleeper for RecordingSleeper {
+    type Sleep = Ready<()>;
+
+    fn sleep(&self, dur: Duration) -> Self::Sleep {
+        self.lock().push(dur);
+        ready(())
+    }
+}
+
+impl backon::BlockingSleeper for RecordingSleeper {
+    fn sleep(&self, dur: Duration) {
+        self.lock().push(dur);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use backon::{BlockingRetryable, Retryable};
+
+    fn config() -> BackoffConfig {
+        BackoffConfig::Exponential(ExponentialBackoffConfig {
+            initial_delay: Duration::from_secs(1),
+            factor: 2_f32,
+            max_delay: Duration::from_secs(4),
+            max_retries: 4,
+            max_total_delay: Duration::from_secs(1000),
+            jitter: Jitter::Random,
+        })
+        .with_jitter_seed(7)
+    }
+
+    #[tokio::test]
+    async fn records_async_retries() {
+        let sleeper = RecordingSleeper::new();
+
+        let result = (|| async { Err::<(), _>("failure") })
+            .retry(config())
+            .sleep(sleeper.clone())
+            .await;
+
+        assert_eq!(result, Err("failure"));
+        assert_eq!(sleeper.delays().len(), 4);
+        sleeper.assert_schedule(&config());
+        sleeper.assert_within_bounds(&config());
+    }
+
+    #[test]
+    fn records_blocking_retries() {
+        let sleeper = RecordingSleeper::new();
+        let mut attempts = 0;
+
+        let result = (|| {
+            attempts += 1;
+            if attempts < 3 {
+                Err("failure")
+            } else {
+                Ok(attempts)
+            }
+        })
+        .retry(config())
+        .sleep(sleeper.clone())
+        .call();
+
+        assert_eq!(result, Ok(3));
+        assert_eq!(sleeper.delays().len(), 2);
+        sleeper.assert_within_bounds(&config());
+
+        sleeper.clear();
+        assert_eq!(sleeper.delays(), vec![]);
+        assert_eq!(sleeper.total_delay(), Duration::ZERO);
+    }
+
+    #[test]
+    #[should_panic(expected = "recorded delays differ from the schedule")]
+    fn assert_schedule_fails_on_different_delays() {
+        let sleeper = RecordingSleeper::new();
+        backon::BlockingSleeper::sleep(&sleeper, Duration::from_secs(1));
+
+        sleeper.assert_schedule(&config());
+    }
+
+    #[test]
+    #[should_panic(expected = "is outside of")]
+    fn assert_within_bounds_fails_on_delay_out_of_bounds() {
+        let sleeper = RecordingSleeper::new();
+        backon::BlockingSleeper::sleep(&sleeper, Duration::from_millis(500));
+
+        sleeper.assert_within_bounds(&config());
+    }
+
+    fn unlimited() -> BackoffConfig {
+        BackoffConfig::Constant(
+            ConstantBackoffConfig::new()
+                .delay(Duration::from_secs(1))
+                .max_retries(usize::MAX)
+                .jitter(Jitter::Disabled),
+        )
+    }
+
+    #[test]
+    fn assert_within_bounds_of_unlimited_retries() {
+        let sleeper = RecordingSleeper::new();
+        backon::BlockingSleeper::sleep(&sleeper, Duration::from_secs(1));
+        backon::BlockingSleeper::sleep(&sleeper, Duration::from_secs(1));
+
+        sleeper.assert_within_bounds(&unlimited());
+    }
+
+    #[test]
+    #[should_panic(expected = "recorded delays differ from the schedule")]
+    fn assert_schedule_of_unlimited_retries_fails() {
+        let sleeper = RecordingSleeper::new();
+        backon::BlockingSleeper::sleep(&sleeper, Duration::from_secs(1));
+
+        sleeper.assert_schedule(&unlimited());
+    }
+}
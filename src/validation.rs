@@ -0,0 +1,340 @@
+use crate::*;
+use std::fmt::{Display, Formatter};
+
+#[derive(Debug, Clone, PartialEq)]
+/// Describes why a [BackoffConfig] is invalid - see [BackoffConfig::validate].
+pub struct ValidationError {
+    /// Name of the offending field.
+    pub field: &'static str,
+
+    /// Human-readable description of the problem.
+    pub message: String,
+}
+
+impl ValidationError {
+    pub(crate) fn new(field: &'static str, message: impl Into<String>) -> ValidationError {
+        ValidationError {
+            field,
+            message: message.into(),
+        }
+    }
+}
+
+impl Display for ValidationError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "invalid `{}`: {}", self.field, self.message)
+    }
+}
+
+impl std::error::Error for ValidationError {}
+
+impl BackoffConfig {
+    /// Checks that this [BackoffConfig] describes a sensible schedule.
+    ///
+    /// Deserialization accepts any well-typed values, so configs loaded at runtime should be validated before use.
+    pub fn validate(&self) -> Result<(), ValidationError> {
+        match self {
+            BackoffConfig::Constant(_) => Ok(()),
+
+            BackoffConfig::Exponential(config) => {
+                if !config.factor.is_finite() || config.factor < 1.0 {
+                    return Err(ValidationError::new(
+                        "factor",
+                        format!("must be a finite number >= 1.0, got {}", config.factor),
+                    ));
+                }
+
+                if config.initial_delay > config.max_delay {
+                    return Err(ValidationError::new(
+                        "initial_delay",
+                        format!(
+                            "must not exceed `max_delay` ({:?}), got {:?}",
+                            config.max_delay, config.initial_delay
+                        ),
+                    ));
+                }
+
+                Ok(())
+            }
+
+            BackoffConfig::Fibonacci(config) => {
+                if config.initial_delay > config.max_delay {
+                    return Err(ValidationError::new(
+                        "initial_delay",
+                        format!(
+                            "must not exceed `max_delay` ({:?}), got {:?}",
+                            config.max_delay, config.initial_delay
+                        ),
+                    ));
+                }
+
+                Ok(())
+            }
+
+            BackoffConfig::Adaptive(config) => {
+                if !config.increase_factor.is_finite() || config.increase_factor < 1.0 {
+                    return Err(ValidationError::new(
+                        "increase_factor",
+                        format!(
+                            "must be a finite number >= 1.0, got {}",
+                            config.increase_factor
+                        ),
+                    ));
+                }
+
+                if config.min_delay > config.max_delay {
+                    return Err(ValidationError::new(
+                        "min_delay",
+                        format!(
+                            "must not exceed `max_delay` ({:?}), got {:?}",
+                            config.max_delay, config.min_delay
+                        ),
+                    ));
+                }
+
+                if !(config.min_delay..=config.max_delay).contains(&config.initial_delay) {
+                    return Err(ValidationError::new(
+                        "initial_delay",
+                        format!(
+                            "must be within `min_delay` ({:?}) and `max_delay` ({:?}), got {:?}",
+                            config.min_delay, config.max_delay, config.initial_delay
+                        ),
+                    ));
+                }
+
+                Ok(())
+            }
+
+            BackoffConfig::RandomizedExponential(config) => {
+                if !(0.0..=1.0).contains(&config.randomization_factor) {
+                    return Err(ValidationError::new(
+                        "randomization_factor",
+                        format!(
+                            "must be a number within 0.0 and 1.0, got {}",
+                            config.randomization_factor
+                        ),
+                    ));
+                }
+
+                if !config.multiplier.is_finite() || config.multiplier < 1.0 {
+                    return Err(ValidationError::new(
+                        "multiplier",
+                        format!("must be a finite number >= 1.0, got {}", config.multiplier),
+                    ));
+                }
+
+                if config.initial_interval > config.max_interval {
+                    return Err(ValidationError::new(
+                        "initial_interval",
+                        format!(
+                            "must not exceed `max_interval` ({:?}), got {:?}",
+                            config.max_interval, config.initial_interval
+                        ),
+                    ));
+                }
+
+                Ok(())
+            }
+
+            BackoffConfig::Polynomial(config) => {
+                if !config.exponent.is_finite() || config.exponent < 0.0 {
+                    return Err(ValidationError::new(
+                        "exponent",
+                        format!("must be a finite number >= 0.0, got {}", config.exponent),
+                    ));
+                }
+
+                if config.base > config.max_delay {
+                    return Err(ValidationError::new(
+                        "base",
+                        format!(
+                            "must not exceed `max_delay` ({:?}), got {:?}",
+                            config.max_delay, config.base
+                        ),
+                    ));
+                }
+
+                Ok(())
+            }
+
+            BackoffConfig::Random(config) => {
+                if config.min_delay > config.max_delay {
+                    return Err(ValidationError::new(
+                        "min_delay",
+                        format!(
+                            "must not exceed `max_delay` ({:?}), got {:?}",
+                            config.max_delay, config.min_delay
+                        ),
+                    ));
+                }
+
+                Ok(())
+            }
+
+            BackoffConfig::NoBackoff => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    #[test]
+    fn defaults_are_valid() {
+        assert_eq!(
+            BackoffConfig::Constant(ConstantBackoffConfig::default()).validate(),
+            Ok(())
+        );
+        assert_eq!(
+            BackoffConfig::Exponential(ExponentialBackoffConfig::default()).validate(),
+            Ok(())
+        );
+        assert_eq!(
+            BackoffConfig::Fibonacci(FibonacciBackoffConfig::default()).validate(),
+            Ok(())
+        );
+        assert_eq!(
+            BackoffConfig::Adaptive(AdaptiveBackoffConfig::default()).validate(),
+            Ok(())
+        );
+        assert_eq!(
+            BackoffConfig::RandomizedExponential(RandomizedExponentialBackoffConfig::default())
+                .validate(),
+            Ok(())
+        );
+        assert_eq!(
+            BackoffConfig::Polynomial(PolynomialBackoffConfig::default()).validate(),
+            Ok(())
+        );
+        assert_eq!(
+            BackoffConfig::Random(RandomBackoffConfig::default()).validate(),
+            Ok(())
+        );
+        assert_eq!(BackoffConfig::NoBackoff.validate(), Ok(()));
+    }
+
+    #[test]
+    fn rejects_shrinking_or_non_finite_factor() {
+        for factor in [0.5, f32::NAN, f32::INFINITY] {
+            let config = BackoffConfig::Exponential(ExponentialBackoffConfig {
+                factor,
+                ..Default::default()
+            });
+
+            assert_eq!(config.validate().unwrap_err().field, "factor");
+        }
+    }
+
+    #[test]
+    fn rejects_initial_delay_above_max_delay() {
+        let exponential = BackoffConfig::Exponential(ExponentialBackoffConfig {
+            initial_delay: Duration::from_secs(2),
+            max_delay: Duration::from_secs(1),
+            ..Default::default()
+        });
+        let fibonacci = BackoffConfig::Fibonacci(FibonacciBackoffConfig {
+            initial_delay: Duration::from_secs(2),
+            max_delay: Duration::from_secs(1),
+            ..Default::default()
+        });
+
+        assert_eq!(
+            exponential.validate().unwrap_err().to_string(),
+            "invalid `initial_delay`: must not exceed `max_delay` (1s), got 2s"
+        );
+        assert_eq!(fibonacci.validate().unwrap_err().field, "initial_delay");
+    }
+
+    #[test]
+    fn rejects_inconsistent_adaptive_bounds() {
+        let shrinking = BackoffConfig::Adaptive(AdaptiveBackoffConfig {
+            increase_factor: 0.5,
+            ..Default::default()
+        });
+        let inverted = BackoffConfig::Adaptive(AdaptiveBackoffConfig {
+            min_delay: Duration::from_secs(2),
+            max_delay: Duration::from_secs(1),
+            ..Default::default()
+        });
+        let outside = BackoffConfig::Adaptive(AdaptiveBackoffConfig {
+            initial_delay: Duration::from_millis(10),
+            ..Default::default()
+        });
+
+        assert_eq!(shrinking.validate().unwrap_err().field, "increase_factor");
+        assert_eq!(inverted.validate().unwrap_err().field, "min_delay");
+        assert_eq!(outside.validate().unwrap_err().field, "initial_delay");
+    }
+
+    #[test]
+    fn rejects_inconsistent_randomized_exponential_config() {
+        let fields = [
+            RandomizedExponentialBackoffConfig {
+                randomization_factor: 1.5,
+                ..Default::default()
+            },
+            RandomizedExponentialBackoffConfig {
+                randomization_factor: f32::NAN,
+                ..Default::default()
+            },
+            RandomizedExponentialBackoffConfig {
+                multiplier: 0.5,
+                ..Default::default()
+            },
+            RandomizedExponentialBackoffConfig {
+                initial_interval: Duration::from_secs(120),
+                ..Default::default()
+            },
+        ]
+        .map(|config| {
+            BackoffConfig::RandomizedExponential(config)
+                .validate()
+                .unwrap_err()
+                .field
+        });
+
+        assert_eq!(
+            fields,
+            [
+                "randomization_factor",
+                "randomization_factor",
+                "multiplier",
+                "initial_interval"
+            ]
+        );
+    }
+
+    #[test]
+    fn rejects_inconsistent_polynomial_config() {
+        let negative = BackoffConfig::Polynomial(PolynomialBackoffConfig {
+            exponent: -1.0,
+            ..Default::default()
+        });
+        let base_above_max_delay = BackoffConfig::Polynomial(PolynomialBackoffConfig {
+            max_delay: Duration::from_secs(10),
+            ..Default::default()
+        });
+
+        assert_eq!(negative.validate().unwrap_err().field, "exponent");
+        assert_eq!(
+            base_above_max_delay.validate().unwrap_err().to_string(),
+            "invalid `base`: must not exceed `max_delay` (10s), got 15s"
+        );
+    }
+
+    #[test]
+    fn rejects_inverted_random_range() {
+        let inverted = BackoffConfig::Random(RandomBackoffConfig {
+            min_delay: Duration::from_secs(2),
+            max_delay: Duration::from_secs(1),
+            ..Default::default()
+        });
+
+        assert_eq!(
+            inverted.validate().unwrap_err().to_string(),
+            "invalid `min_delay`: must not exceed `max_delay` (1s), got 2s"
+        );
+    }
+}
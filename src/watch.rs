@@ -0,0 +1,210 @@
+use crate::*;
+use serde::Deserialize;
+use std::fmt::{Display, Formatter};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::sync::mpsc::{RecvTimeoutError, Sender, channel};
+use std::thread::JoinHandle;
+use std::time::Duration;
+
+/// Callback invoked with every [ReloadError] of a [BackoffConfigWatcher].
+type ErrorCallback = Arc<dyn Fn(&ReloadError) + Send + Sync>;
+
+/// Watches a TOML or JSON file and reloads a [BackoffConfig] section of it into a [SharedBackoffConfig].
+///
+/// The file format is chosen by its extension (`.toml` or `.json`). On every change the section at
+/// [BackoffConfigWatcher::section] (`backoff` by default, nested sections are separated by `.`) is deserialized and
+/// validated with [BackoffConfig::validate]. Only valid configs are stored, so a bad edit never replaces a good
+/// config - it is reported to the [BackoffConfigWatcher::on_error] callback instead.
+pub struct BackoffConfigWatcher {
+    path: PathBuf,
+    section: String,
+    poll_interval: Duration,
+    target: SharedBackoffConfig,
+    on_error: ErrorCallback,
+    last_contents: Option<Vec<u8>>,
+}
+
+impl BackoffConfigWatcher {
+    /// Creates a new [BackoffConfigWatcher] that reloads the file at `path` into `target`.
+    pub fn new(path: impl Into<PathBuf>, target: SharedBackoffConfig) -> BackoffConfigWatcher {
+        BackoffConfigWatcher {
+            path: path.into(),
+            section: "backoff".to_string(),
+            poll_interval: Duration::from_secs(1),
+            target,
+            on_error: Arc::new(|_| {}),
+            last_contents: None,
+        }
+    }
+
+    /// Sets the `.`-separated path of the [BackoffConfig] section within the file.
+    ///
+    /// Defaults to `backoff`.
+    pub fn section(mut self, section: impl Into<String>) -> BackoffConfigWatcher {
+        self.section = section.into();
+        self
+    }
+
+    /// Sets how often the file is checked for changes.
+    ///
+    /// Defaults to `1 second`.
+    pub fn poll_interval(mut self, poll_interval: Duration) -> BackoffConfigWatcher {
+        self.poll_interval = poll_interval;
+        self
+    }
+
+    /// Sets the callback that is invoked whenever the file cannot be reloaded.
+    ///
+    /// Defaults to ignoring errors.
+    pub fn on_error(
+        mut self,
+        on_error: impl Fn(&ReloadError) + Send + Sync + 'static,
+    ) -> BackoffConfigWatcher {
+        self.on_error = Arc::new(on_error);
+        self
+    }
+
+    /// Checks the file once, storing its [BackoffConfig] if the file changed since the last check.
+    ///
+    /// Returns whether the file changed. Errors are returned but are not passed to the
+    /// [BackoffConfigWatcher::on_error] callback.
+    pub fn reload(&mut self) -> Result<bool, ReloadError> {
+        let contents = std::fs::read(&self.path).map_err(ReloadError::Io)?;
+        if self.last_contents.as_ref() == Some(&contents) {
+            return Ok(false);
+        }
+        self.last_contents = Some(contents.clone());
+
+        let config = parse(&self.path, &self.section, &contents)?;
+        config.validate().map_err(ReloadError::Invalid)?;
+        self.target.store(config);
+
+        Ok(true)
+    }
+
+    /// Spawns a background thread that checks the file every [BackoffConfigWatcher::poll_interval].
+    ///
+    /// The file is checked immediately. Watching stops once the returned [WatchHandle] is dropped.
+    pub fn spawn(mut self) -> std::io::Result<WatchHandle> {
+        let (stop, stopped) = channel();
+
+        let thread = std::thread::Builder::new()
+            .name("backoff-config-watcher".to_string())
+            .spawn(move || {
+                loop {
+                    if let Err(error) = self.reload() {
+                        (self.on_error)(&error);
+                    }
+
+                    match stopped.recv_timeout(self.poll_interval) {
+                        Err(RecvTimeoutError::Timeout) => continue,
+                        Ok(()) | Err(RecvTimeoutError::Disconnected) => break,
+                    }
+                }
+            })?;
+
+        Ok(WatchHandle {
+            stop,
+            thread: Some(thread),
+        })
+    }
+}
+
+fn parse(path: &Path, section: &str, contents: &[u8]) -> Result<BackoffConfig, ReloadError> {
+    let contents = std::str::from_utf8(contents).map_err(|e| ReloadError::Parse(e.to_string()))?;
+    let parse_error = |e: &dyn Display| ReloadError::Parse(e.to_string());
+    let missing_section = || ReloadError::MissingSection(section.to_string());
+
+    match path.extension().and_then(|extension| extension.to_str()) {
+        Some("toml") => {
+            let mut value: toml::Value = toml::from_str(contents).map_err(|e| parse_error(&e))?;
+            for key in section.split('.') {
+                value = value
+                    .as_table_mut()
+                    .and_then(|table| table.remove(key))
+                    .ok_or_else(missing_section)?;
+            }
+            BackoffConfig::deserialize(value).map_err(|e| parse_error(&e))
+        }
+
+        Some("json") => {
+            let mut value: serde_json::Value =
+                serde_json::from_str(contents).map_err(|e| parse_error(&e))?;
+            for key in section.split('.') {
+                value = value
+                    .as_object_mut()
+                    .and_then(|object| object.remove(key))
+                    .ok_or_else(missing_section)?;
+            }
+            BackoffConfig::deserialize(value).map_err(|e| parse_error(&e))
+        }
+
+        _ => Err(ReloadError::UnsupportedFormat(path.to_path_buf())),
+    }
+}
+
+#[derive(Debug)]
+/// Handle to a running [BackoffConfigWatcher] - see [BackoffConfigWatcher::spawn].
+///
+/// Dropping the handle stops the watcher and waits for its thread to finish.
+pub struct WatchHandle {
+    stop: Sender<()>,
+    thread: Option<JoinHandle<()>>,
+}
+
+impl Drop for WatchHandle {
+    fn drop(&mut self) {
+        let _ = self.stop.send(());
+        if let Some(thread) = self.thread.take() {
+            let _ = thread.join();
+        }
+    }
+}
+
+#[derive(Debug)]
+/// Reasons why a [BackoffConfigWatcher] could not reload its file.
+pub enum ReloadError {
+    /// The file could not be read.
+    Io(std::io::Error),
+
+    /// The file is not a valid TOML / JSON document, or its section is not a valid [BackoffConfig].
+    Parse(String),
+
+    /// The file does not contain the configured section.
+    MissingSection(String),
+
+    /// The section is a [BackoffConfig] that failed [BackoffConfig::validate].
+    Invalid(ValidationError),
+
+    /// The file extension is neither `.toml` nor `.json`.
+    UnsupportedFormat(PathBuf),
+}
+
+impl Display for ReloadError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ReloadError::Io(e) => write!(f, "failed to read backoff config: {e}"),
+            ReloadError::Parse(e) => write!(f, "failed to parse backoff config: {e}"),
+            ReloadError::MissingSection(section) => {
+                write!(f, "backoff config section `{section}` is missing")
+            }
+            ReloadError::Invalid(e) => write!(f, "backoff config is invalid: {e}"),
+            ReloadError::UnsupportedFormat(path) => write!(
+                f,
+                "unsupported backoff config format of `{}`, expected `.toml` or `.json`",
+                path.display()
+            ),
+        }
+    }
+}
+
+impl std::error::Error for ReloadError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            ReloadError::Io(e) => Some(e),
+            ReloadError::Invalid(e) => Some(e),
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,216 @@
+#![cfg(feature = "cli")]
+#![allow(clippy::result_large_err)]
+
+use std::path::Path;
+use std::process::{Command, Output};
+
+fn run(directory: &Path, args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_backoff-config"))
+        .current_dir(directory)
+        .args(args)
+        .output()
+        .unwrap()
+}
+
+fn stdout(output: &Output) -> String {
+    String::from_utf8_lossy(&output.stdout).into_owned()
+}
+
+#[test]
+fn previews_valid_toml_file() {
+    figment::Jail::expect_with(|jail| {
+        jail.create_file(
+            "config.toml",
+            r#"
+                [backoff]
+                strategy = "Exponential"
+                initial_delay = "100ms"
+                factor = 2.0
+                max_delay = "800ms"
+                max_retries = 5
+                jitter_enabled = false
+            "#,
+        )?;
+
+        let output = run(jail.directory(), &["config.toml"]);
+
+        assert_eq!(output.status.code(), Some(0));
+        assert_eq!(
+            stdout(&output),
+            "\
+backoff: exponential 100ms×2.0 up to 800ms, 5 retries, ≤60s total
+  attempt  delay                  cumulative
+        1  100ms                  100ms
+        2  200ms                  300ms
+        3  400ms                  700ms
+        4  800ms                  1.5s
+        5  800ms                  2.3s
+  total: 2.3s over 5 retries
+  warning: `jitter_enabled` is deprecated since schema version 2, use `jitter = false` instead
+
+1 backoff config(s) checked, 0 problem(s) found
+"
+        );
+
+        Ok(())
+    });
+}
+
+#[test]
+fn reports_every_invalid_config() {
+    figment::Jail::expect_with(|jail| {
+        jail.create_file(
+            "config.yaml",
+            r#"
+backoff:
+  strategy: Fibonacci
+  initial_delay: 2s
+  max_delay: 1s
+  max_retry: 3
+circuit_breaker:
+  open_cooldown:
+    strategy: Fibonaci
+"#,
+        )?;
+
+        let output = run(jail.directory(), &["config.yaml"]);
+        let stdout = stdout(&output);
+
+        assert_eq!(output.status.code(), Some(1));
+        assert!(stdout.contains(
+            "backoff: fibonacci 2s up to 1s, 4 retries, jitter\n  \
+             problem: unknown key `max_retry` for strategy `Fibonacci`, did you mean `max_retries`?\n  \
+             problem: invalid `initial_delay`: must not exceed `max_delay`"
+        ));
+        assert!(stdout.contains(
+            "circuit_breaker.open_cooldown: invalid\n  problem: failed to deserialize: unknown strategy `Fibonaci`"
+        ));
+        assert!(stdout.contains("did you mean `Fibonacci`?"));
+        assert!(stdout.contains("2 backoff config(s) checked, 3 problem(s) found"));
+
+        Ok(())
+    });
+}
+
+#[test]
+fn fails_when_exceeding_budget() {
+    figment::Jail::expect_with(|jail| {
+        jail.create_file(
+            "config.json",
+            r#"{ "backoff": { "strategy": "Constant", "delay": "1s", "max_retries": 3 } }"#,
+        )?;
+
+        let within = run(jail.directory(), &["--budget", "6s", "config.json"]);
+        assert_eq!(within.status.code(), Some(0));
+
+        let exceeding = run(jail.directory(), &["--budget", "5s", "config.json"]);
+        assert_eq!(exceeding.status.code(), Some(1));
+        assert!(
+            stdout(&exceeding)
+                .contains("problem: worst case total delay of 6s exceeds the budget of 5s")
+        );
+
+        Ok(())
+    });
+}
+
+#[test]
+fn reads_env() {
+    figment::Jail::expect_with(|jail| {
+        jail.set_env("CONFIG__BACKOFF__STRATEGY", "Constant");
+        jail.set_env("CONFIG__BACKOFF__DELAY", "1s");
+        jail.set_env("CONFIG__BACKOFF__MAX_RETRIES", "2");
+        jail.set_env("CONFIG__BACKOFF__JITTER_ENABLED", "false");
+
+        let output = run(jail.directory(), &["--env", "CONFIG__"]);
+
+        assert_eq!(output.status.code(), Some(0));
+        assert!(stdout(&output).contains("backoff: constant 1s, 2 retries\n"));
+        assert!(stdout(&output).contains("total: 2s over 2 retries"));
+
+        Ok(())
+    });
+}
+
+#[test]
+fn summarizes_unlimited_schedule() {
+    figment::Jail::expect_with(|jail| {
+        jail.create_file(
+            "config.json",
+            r#"{ "backoff": { "strategy": "Constant", "delay": "1s", "max_retries": 18446744073709551615, "jitter": false } }"#,
+        )?;
+
+        let output = run(jail.directory(), &["config.json"]);
+        let stdout = stdout(&output);
+
+        assert_eq!(output.status.code(), Some(0));
+        assert!(stdout.contains("       25  1s                     25s\n"));
+        assert!(!stdout.contains("       26  "));
+        assert!(stdout.contains("        …  18446744073709551590 more retries\n"));
+        assert!(stdout.contains("warning: retries are unlimited"));
+
+        let exceeding = run(jail.directory(), &["--budget", "1h", "config.json"]);
+        assert_eq!(exceeding.status.code(), Some(1));
+
+        Ok(())
+    });
+}
+
+#[test]
+fn fails_without_configs() {
+    figment::Jail::expect_with(|jail| {
+        jail.create_file("config.toml", "[backoff]\ndelay = \"1s\"")?;
+
+        assert_eq!(
+            run(jail.directory(), &["config.toml"]).status.code(),
+            Some(1)
+        );
+        assert_eq!(
+            run(jail.directory(), &["config.ini"]).status.code(),
+            Some(2)
+        );
+        assert_eq!(run(jail.directory(), &[]).status.code(), Some(2));
+
+        Ok(())
+    });
+}
+
+#[test]
+fn lints_shorthands_at_given_paths() {
+    figment::Jail::expect_with(|jail| {
+        jail.create_file(
+            "config.toml",
+            r#"
+                backoff = "none"
+
+                [client]
+                retry = "1.5"
+            "#,
+        )?;
+
+        assert_eq!(
+            run(jail.directory(), &["config.toml"]).status.code(),
+            Some(1)
+        );
+
+        let output = run(
+            jail.directory(),
+            &["--path", "backoff", "--path", "client.retry", "config.toml"],
+        );
+        let stdout = stdout(&output);
+
+        assert_eq!(output.status.code(), Some(1));
+        assert!(stdout.contains("backoff: no backoff"), "{stdout}");
+        assert!(stdout.contains("client.retry: invalid"), "{stdout}");
+        assert!(stdout.contains("2 backoff config(s) checked, 1 problem(s) found"));
+
+        assert_eq!(
+            run(jail.directory(), &["--path", "missing", "config.toml"])
+                .status
+                .code(),
+            Some(2)
+        );
+
+        Ok(())
+    });
+}
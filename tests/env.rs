@@ -17,12 +17,7 @@ fn constant_backoff_with_defaults() {
         assert_eq!(
             config,
             Config {
-                backoff: BackoffConfig::Constant(ConstantBackoffConfig {
-                    delay: defaults::delay(),
-                    max_retries: defaults::max_retries(),
-                    jitter_enabled: defaults::jitter_enabled(),
-                    jitter_seed: defaults::jitter_seed(),
-                })
+                backoff: BackoffConfig::Constant(ConstantBackoffConfig::new())
             }
         );
 
@@ -47,12 +42,12 @@ fn constant_backoff_with_custom_values() {
         assert_eq!(
             config,
             Config {
-                backoff: BackoffConfig::Constant(ConstantBackoffConfig {
-                    delay: Duration::from_secs(123),
-                    max_retries: 456,
-                    jitter_enabled: false,
-                    jitter_seed: Some(1337),
-                }),
+                backoff: BackoffConfig::Constant(
+                    ConstantBackoffConfig::new()
+                        .delay(Duration::from_secs(123))
+                        .max_retries(456)
+                        .jitter(Jitter::Disabled)
+                ),
             }
         );
 
@@ -72,15 +67,7 @@ fn exponential_backoff_with_defaults() {
         assert_eq!(
             config,
             Config {
-                backoff: BackoffConfig::Exponential(ExponentialBackoffConfig {
-                    initial_delay: defaults::delay(),
-                    factor: defaults::factor(),
-                    max_delay: defaults::max_delay(),
-                    max_retries: defaults::max_retries(),
-                    max_total_delay: defaults::max_total_delay(),
-                    jitter_enabled: defaults::jitter_enabled(),
-                    jitter_seed: defaults::jitter_seed(),
-                })
+                backoff: BackoffConfig::Exponential(ExponentialBackoffConfig::new())
             }
         );
 
@@ -108,15 +95,15 @@ fn exponential_backoff_with_custom_values() {
         assert_eq!(
             config,
             Config {
-                backoff: BackoffConfig::Exponential(ExponentialBackoffConfig {
-                    initial_delay: Duration::from_millis(750),
-                    factor: 3.5,
-                    max_delay: Duration::from_secs(20),
-                    max_retries: 10,
-                    max_total_delay: Duration::from_secs(90),
-                    jitter_enabled: false,
-                    jitter_seed: Some(1337),
-                })
+                backoff: BackoffConfig::Exponential(
+                    ExponentialBackoffConfig::new()
+                        .initial_delay(Duration::from_millis(750))
+                        .factor(3.5)
+                        .max_delay(Duration::from_secs(20))
+                        .max_retries(10)
+                        .max_total_delay(Duration::from_secs(90))
+                        .jitter(Jitter::Disabled)
+                )
             }
         );
 
@@ -136,13 +123,7 @@ fn fibonacci_backoff_with_defaults() {
         assert_eq!(
             config,
             Config {
-                backoff: BackoffConfig::Fibonacci(FibonacciBackoffConfig {
-                    initial_delay: defaults::delay(),
-                    max_delay: defaults::max_delay(),
-                    max_retries: defaults::max_retries(),
-                    jitter_enabled: defaults::jitter_enabled(),
-                    jitter_seed: defaults::jitter_seed(),
-                })
+                backoff: BackoffConfig::Fibonacci(FibonacciBackoffConfig::new())
             }
         );
 
@@ -168,13 +149,71 @@ fn fibonacci_backoff_with_custom_values() {
         assert_eq!(
             config,
             Config {
-                backoff: BackoffConfig::Fibonacci(FibonacciBackoffConfig {
-                    initial_delay: Duration::from_millis(1234),
-                    max_delay: Duration::from_nanos(123456789),
-                    max_retries: 10,
-                    jitter_enabled: false,
-                    jitter_seed: Some(1337),
-                })
+                backoff: BackoffConfig::Fibonacci(
+                    FibonacciBackoffConfig::new()
+                        .initial_delay(Duration::from_millis(1234))
+                        .max_delay(Duration::from_nanos(123456789))
+                        .max_retries(10)
+                        .jitter(Jitter::Disabled)
+                )
+            }
+        );
+
+        Ok(())
+    });
+}
+
+#[test]
+fn adaptive_backoff_with_defaults() {
+    figment::Jail::expect_with(|jail| {
+        jail.set_env("CONFIG__BACKOFF__STRATEGY", "Adaptive");
+
+        let config = figment::Figment::new()
+            .merge(Env::prefixed("CONFIG__").split("__"))
+            .extract::<Config>()?;
+
+        assert_eq!(
+            config,
+            Config {
+                backoff: BackoffConfig::Adaptive(AdaptiveBackoffConfig::new())
+            }
+        );
+
+        Ok(())
+    });
+}
+
+#[test]
+fn adaptive_backoff_with_custom_values() {
+    figment::Jail::expect_with(|jail| {
+        jail.set_env("CONFIG__BACKOFF__STRATEGY", "Adaptive");
+
+        jail.set_env("CONFIG__BACKOFF__INITIAL_DELAY", "750ms");
+        jail.set_env("CONFIG__BACKOFF__MIN_DELAY", "250ms");
+        jail.set_env("CONFIG__BACKOFF__MAX_DELAY", "20s");
+        jail.set_env("CONFIG__BACKOFF__INCREASE_FACTOR", "1.5");
+        jail.set_env("CONFIG__BACKOFF__DECREASE_STEP", "50ms");
+        jail.set_env("CONFIG__BACKOFF__MAX_RETRIES", "10");
+        jail.set_env("CONFIG__BACKOFF__JITTER_ENABLED", "false");
+        jail.set_env("CONFIG__BACKOFF__JITTER_SEED", "1337");
+
+        let config = figment::Figment::new()
+            .merge(Env::prefixed("CONFIG__").split("__"))
+            .extract::<Config>()?;
+
+        assert_eq!(
+            config,
+            Config {
+                backoff: BackoffConfig::Adaptive(
+                    AdaptiveBackoffConfig::new()
+                        .initial_delay(Duration::from_millis(750))
+                        .min_delay(Duration::from_millis(250))
+                        .max_delay(Duration::from_secs(20))
+                        .increase_factor(1.5)
+                        .decrease_step(Duration::from_millis(50))
+                        .max_retries(10)
+                        .jitter(Jitter::Disabled)
+                )
             }
         );
 
@@ -201,3 +240,186 @@ fn no_backoff() {
         Ok(())
     });
 }
+
+#[test]
+fn exponential_backoff_with_alternative_duration_formats() {
+    figment::Jail::expect_with(|jail| {
+        jail.set_env("CONFIG__BACKOFF__STRATEGY", "Exponential");
+
+        jail.set_env("CONFIG__BACKOFF__INITIAL_DELAY", "250");
+        jail.set_env("CONFIG__BACKOFF__MAX_DELAY", "PT1M30S");
+        jail.set_env("CONFIG__BACKOFF__MAX_TOTAL_DELAY", "P1D");
+
+        let config = figment::Figment::new()
+            .merge(Env::prefixed("CONFIG__").split("__"))
+            .extract::<Config>()?;
+
+        assert_eq!(
+            config,
+            Config {
+                backoff: BackoffConfig::Exponential(
+                    ExponentialBackoffConfig::new()
+                        .initial_delay(Duration::from_millis(250))
+                        .max_delay(Duration::from_secs(90))
+                        .max_total_delay(Duration::from_secs(86_400))
+                )
+            }
+        );
+
+        Ok(())
+    });
+}
+
+#[test]
+fn exponential_backoff_with_duration_unit() {
+    figment::Jail::expect_with(|jail| {
+        jail.set_env("CONFIG__BACKOFF__STRATEGY", "Exponential");
+        jail.set_env("CONFIG__BACKOFF__DURATION_UNIT", "seconds");
+
+        jail.set_env("CONFIG__BACKOFF__INITIAL_DELAY", "2");
+        jail.set_env("CONFIG__BACKOFF__MAX_DELAY", "1.5");
+        jail.set_env("CONFIG__BACKOFF__MAX_TOTAL_DELAY", "500ms");
+
+        let config = figment::Figment::new()
+            .merge(Env::prefixed("CONFIG__").split("__"))
+            .extract::<Config>()?;
+
+        assert_eq!(
+            config,
+            Config {
+                backoff: BackoffConfig::Exponential(
+                    ExponentialBackoffConfig::new()
+                        .initial_delay(Duration::from_secs(2))
+                        .max_delay(Duration::from_millis(1500))
+                        .max_total_delay(Duration::from_millis(500))
+                )
+            }
+        );
+
+        Ok(())
+    });
+}
+
+#[test]
+fn strategy_aliases_and_lowercase_names() {
+    figment::Jail::expect_with(|jail| {
+        for (strategy, expected) in [
+            (
+                "exponential",
+                BackoffConfig::Exponential(Default::default()),
+            ),
+            ("exp", BackoffConfig::Exponential(Default::default())),
+            ("fib", BackoffConfig::Fibonacci(Default::default())),
+            ("fixed", BackoffConfig::Constant(Default::default())),
+            ("constant", BackoffConfig::Constant(Default::default())),
+            ("off", BackoffConfig::NoBackoff),
+            ("DISABLED", BackoffConfig::NoBackoff),
+        ] {
+            jail.set_env("CONFIG__BACKOFF__STRATEGY", strategy);
+
+            let config = figment::Figment::new()
+                .merge(Env::prefixed("CONFIG__").split("__"))
+                .extract::<Config>()?;
+
+            assert_eq!(config, Config { backoff: expected }, "{strategy}");
+        }
+
+        Ok(())
+    });
+}
+
+#[test]
+fn scalar_shorthands() {
+    figment::Jail::expect_with(|jail| {
+        for (backoff, expected) in [
+            ("off", BackoffConfig::NoBackoff),
+            (
+                "2s",
+                BackoffConfig::Constant(ConstantBackoffConfig::new().delay(Duration::from_secs(2))),
+            ),
+            (
+                "3",
+                BackoffConfig::Constant(ConstantBackoffConfig::new().max_retries(3)),
+            ),
+        ] {
+            jail.set_env("CONFIG__BACKOFF", backoff);
+
+            let config = figment::Figment::new()
+                .merge(Env::prefixed("CONFIG__").split("__"))
+                .extract::<Config>()?;
+
+            assert_eq!(config, Config { backoff: expected }, "{backoff}");
+        }
+
+        Ok(())
+    });
+}
+
+#[test]
+fn env_overrides_toml_shorthand() {
+    figment::Jail::expect_with(|jail| {
+        jail.create_file("config.toml", r#"backoff = "500ms""#)?;
+        jail.set_env("CONFIG__BACKOFF", "none");
+
+        let config = figment::Figment::new()
+            .merge(figment::providers::Data::<figment::providers::Toml>::file(
+                "config.toml",
+            ))
+            .merge(Env::prefixed("CONFIG__").split("__"))
+            .extract::<Config>()?;
+
+        assert_eq!(
+            config,
+            Config {
+                backoff: BackoffConfig::NoBackoff
+            }
+        );
+
+        Ok(())
+    });
+}
+
+#[test]
+fn exported_env_vars_read_back() {
+    let configs = [
+        BackoffConfig::Constant(
+            ConstantBackoffConfig::new()
+                .delay(Duration::from_millis(1_500))
+                .jitter(Jitter::Seeded(7)),
+        ),
+        BackoffConfig::Exponential(
+            ExponentialBackoffConfig::new()
+                .factor(1.5)
+                .max_total_delay(Duration::MAX)
+                .jitter(Jitter::Disabled),
+        ),
+        BackoffConfig::Fibonacci(
+            FibonacciBackoffConfig::new().initial_delay(Duration::from_nanos(250)),
+        ),
+        BackoffConfig::Adaptive(AdaptiveBackoffConfig::default()),
+        BackoffConfig::RandomizedExponential(
+            RandomizedExponentialBackoffConfig::new()
+                .randomization_factor(0.25)
+                .jitter(Jitter::Seeded(3)),
+        ),
+        BackoffConfig::Polynomial(PolynomialBackoffConfig::new().exponent(2.5)),
+        BackoffConfig::Random(RandomBackoffConfig::new().max_delay(Duration::from_secs(5))),
+        BackoffConfig::NoBackoff,
+    ];
+
+    for expected in configs {
+        figment::Jail::expect_with(|jail| {
+            for (name, value) in expected.to_env_vars("CONFIG__BACKOFF", "__") {
+                jail.set_env(name, value);
+            }
+
+            let config = figment::Figment::new()
+                .merge(Env::prefixed("CONFIG__").split("__"))
+                .extract::<Config>()?;
+
+            assert_eq!(config, Config { backoff: expected });
+
+            Ok(())
+        });
+    }
+}
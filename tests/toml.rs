@@ -26,12 +26,7 @@ fn constant_backoff_with_defaults() {
         assert_eq!(
             config,
             Config {
-                backoff: BackoffConfig::Constant(ConstantBackoffConfig {
-                    delay: defaults::delay(),
-                    max_retries: defaults::max_retries(),
-                    jitter_enabled: defaults::jitter_enabled(),
-                    jitter_seed: defaults::jitter_seed(),
-                }),
+                backoff: BackoffConfig::Constant(ConstantBackoffConfig::new()),
             }
         );
 
@@ -61,12 +56,12 @@ fn constant_backoff_with_custom_values() {
         assert_eq!(
             config,
             Config {
-                backoff: BackoffConfig::Constant(ConstantBackoffConfig {
-                    delay: Duration::from_secs(123),
-                    max_retries: 456,
-                    jitter_enabled: false,
-                    jitter_seed: Some(1337),
-                }),
+                backoff: BackoffConfig::Constant(
+                    ConstantBackoffConfig::new()
+                        .delay(Duration::from_secs(123))
+                        .max_retries(456)
+                        .jitter(Jitter::Disabled)
+                ),
             }
         );
 
@@ -92,15 +87,7 @@ fn exponential_backoff_with_defaults() {
         assert_eq!(
             config,
             Config {
-                backoff: BackoffConfig::Exponential(ExponentialBackoffConfig {
-                    initial_delay: defaults::delay(),
-                    factor: defaults::factor(),
-                    max_delay: defaults::max_delay(),
-                    max_retries: defaults::max_retries(),
-                    max_total_delay: defaults::max_total_delay(),
-                    jitter_enabled: defaults::jitter_enabled(),
-                    jitter_seed: defaults::jitter_seed(),
-                })
+                backoff: BackoffConfig::Exponential(ExponentialBackoffConfig::new())
             }
         );
 
@@ -133,15 +120,15 @@ fn exponential_backoff_with_custom_values() {
         assert_eq!(
             config,
             Config {
-                backoff: BackoffConfig::Exponential(ExponentialBackoffConfig {
-                    initial_delay: Duration::from_millis(750),
-                    factor: 3.5,
-                    max_delay: Duration::from_secs(20),
-                    max_retries: 10,
-                    max_total_delay: Duration::from_secs(90),
-                    jitter_enabled: false,
-                    jitter_seed: Some(1337),
-                })
+                backoff: BackoffConfig::Exponential(
+                    ExponentialBackoffConfig::new()
+                        .initial_delay(Duration::from_millis(750))
+                        .factor(3.5)
+                        .max_delay(Duration::from_secs(20))
+                        .max_retries(10)
+                        .max_total_delay(Duration::from_secs(90))
+                        .jitter(Jitter::Disabled)
+                )
             }
         );
 
@@ -167,13 +154,7 @@ fn fibonacci_backoff_with_defaults() {
         assert_eq!(
             config,
             Config {
-                backoff: BackoffConfig::Fibonacci(FibonacciBackoffConfig {
-                    initial_delay: defaults::delay(),
-                    max_delay: defaults::max_delay(),
-                    max_retries: defaults::max_retries(),
-                    jitter_enabled: defaults::jitter_enabled(),
-                    jitter_seed: defaults::jitter_seed(),
-                })
+                backoff: BackoffConfig::Fibonacci(FibonacciBackoffConfig::new())
             }
         );
 
@@ -204,13 +185,13 @@ fn fibonacci_backoff_with_custom_values() {
         assert_eq!(
             config,
             Config {
-                backoff: BackoffConfig::Fibonacci(FibonacciBackoffConfig {
-                    initial_delay: Duration::from_millis(750),
-                    max_delay: Duration::from_secs(20),
-                    max_retries: 10,
-                    jitter_enabled: false,
-                    jitter_seed: Some(1337),
-                })
+                backoff: BackoffConfig::Fibonacci(
+                    FibonacciBackoffConfig::new()
+                        .initial_delay(Duration::from_millis(750))
+                        .max_delay(Duration::from_secs(20))
+                        .max_retries(10)
+                        .jitter(Jitter::Disabled)
+                )
             }
         );
 
@@ -218,6 +199,252 @@ fn fibonacci_backoff_with_custom_values() {
     });
 }
 
+#[test]
+fn adaptive_backoff_with_defaults() {
+    figment::Jail::expect_with(|jail| {
+        jail.create_file(
+            CONFIG_TOML_PATH,
+            r#"
+                [backoff]
+                strategy = "Adaptive"
+            "#,
+        )?;
+
+        let config = figment::Figment::new()
+            .merge(Data::<Toml>::file(CONFIG_TOML_PATH))
+            .extract::<Config>()?;
+
+        assert_eq!(
+            config,
+            Config {
+                backoff: BackoffConfig::Adaptive(AdaptiveBackoffConfig::new())
+            }
+        );
+
+        Ok(())
+    });
+}
+
+#[test]
+fn adaptive_backoff_with_custom_values() {
+    figment::Jail::expect_with(|jail| {
+        jail.create_file(
+            CONFIG_TOML_PATH,
+            r#"
+                [backoff]
+                strategy = "Adaptive"
+                initial_delay = "750 ms"
+                min_delay = "250 ms"
+                max_delay = "20 s"
+                increase_factor = 1.5
+                decrease_step = "50 ms"
+                max_retries = 10
+                jitter_enabled = false
+                jitter_seed = 1337
+            "#,
+        )?;
+
+        let config = figment::Figment::new()
+            .merge(Data::<Toml>::file(CONFIG_TOML_PATH))
+            .extract::<Config>()?;
+
+        assert_eq!(
+            config,
+            Config {
+                backoff: BackoffConfig::Adaptive(
+                    AdaptiveBackoffConfig::new()
+                        .initial_delay(Duration::from_millis(750))
+                        .min_delay(Duration::from_millis(250))
+                        .max_delay(Duration::from_secs(20))
+                        .increase_factor(1.5)
+                        .decrease_step(Duration::from_millis(50))
+                        .max_retries(10)
+                        .jitter(Jitter::Disabled)
+                )
+            }
+        );
+
+        Ok(())
+    });
+}
+
+#[test]
+fn randomized_exponential_backoff_with_defaults() {
+    figment::Jail::expect_with(|jail| {
+        jail.create_file(
+            CONFIG_TOML_PATH,
+            r#"
+                [backoff]
+                strategy = "RandomizedExponential"
+            "#,
+        )?;
+
+        let config = figment::Figment::new()
+            .merge(Data::<Toml>::file(CONFIG_TOML_PATH))
+            .extract::<Config>()?;
+
+        assert_eq!(
+            config,
+            Config {
+                backoff: BackoffConfig::RandomizedExponential(
+                    RandomizedExponentialBackoffConfig::new()
+                )
+            }
+        );
+
+        Ok(())
+    });
+}
+
+#[test]
+fn randomized_exponential_backoff_with_custom_values() {
+    figment::Jail::expect_with(|jail| {
+        jail.create_file(
+            CONFIG_TOML_PATH,
+            r#"
+                [backoff]
+                strategy = "RandomizedExponential"
+                initial_interval = "100 ms"
+                randomization_factor = 0.2
+                multiplier = 2.0
+                max_interval = "10 s"
+                max_elapsed_time = "5 min"
+                max_retries = 12
+                jitter = 1337
+            "#,
+        )?;
+
+        let config = figment::Figment::new()
+            .merge(Data::<Toml>::file(CONFIG_TOML_PATH))
+            .extract::<Config>()?;
+
+        assert_eq!(
+            config,
+            Config {
+                backoff: BackoffConfig::RandomizedExponential(
+                    RandomizedExponentialBackoffConfig::new()
+                        .initial_interval(Duration::from_millis(100))
+                        .randomization_factor(0.2)
+                        .multiplier(2.0)
+                        .max_interval(Duration::from_secs(10))
+                        .max_elapsed_time(Duration::from_secs(300))
+                        .max_retries(12)
+                        .jitter(Jitter::Seeded(1337))
+                )
+            }
+        );
+
+        Ok(())
+    });
+}
+
+#[test]
+fn polynomial_backoff_with_custom_values() {
+    figment::Jail::expect_with(|jail| {
+        jail.create_file(
+            CONFIG_TOML_PATH,
+            r#"
+                [backoff]
+                strategy = "Polynomial"
+                base = "30 s"
+                exponent = 3.0
+                scale = "2 s"
+                padding = "5 s"
+                max_delay = "1 day"
+                max_retries = 25
+                jitter = false
+            "#,
+        )?;
+
+        let config = figment::Figment::new()
+            .merge(Data::<Toml>::file(CONFIG_TOML_PATH))
+            .extract::<Config>()?;
+
+        assert_eq!(
+            config,
+            Config {
+                backoff: BackoffConfig::Polynomial(
+                    PolynomialBackoffConfig::new()
+                        .base(Duration::from_secs(30))
+                        .exponent(3.0)
+                        .scale(Duration::from_secs(2))
+                        .padding(Duration::from_secs(5))
+                        .max_delay(Duration::from_secs(86_400))
+                        .max_retries(25)
+                        .jitter(Jitter::Disabled)
+                )
+            }
+        );
+
+        Ok(())
+    });
+}
+
+#[test]
+fn random_backoff_with_custom_values() {
+    figment::Jail::expect_with(|jail| {
+        jail.create_file(
+            CONFIG_TOML_PATH,
+            r#"
+                [backoff]
+                strategy = "Random"
+                min_delay = "5 s"
+                max_delay = "15 s"
+                max_retries = 100
+                seed = 1337
+            "#,
+        )?;
+
+        let config = figment::Figment::new()
+            .merge(Data::<Toml>::file(CONFIG_TOML_PATH))
+            .extract::<Config>()?;
+
+        assert_eq!(
+            config,
+            Config {
+                backoff: BackoffConfig::Random(
+                    RandomBackoffConfig::new()
+                        .min_delay(Duration::from_secs(5))
+                        .max_delay(Duration::from_secs(15))
+                        .max_retries(100)
+                        .seed(Some(1337))
+                )
+            }
+        );
+
+        Ok(())
+    });
+}
+
+#[test]
+fn random_backoff_rejects_inverted_range() {
+    figment::Jail::expect_with(|jail| {
+        jail.create_file(
+            CONFIG_TOML_PATH,
+            r#"
+                [backoff]
+                strategy = "Random"
+                min_delay = "15 s"
+                max_delay = "5 s"
+            "#,
+        )?;
+
+        let error = figment::Figment::new()
+            .merge(Data::<Toml>::file(CONFIG_TOML_PATH))
+            .extract::<Config>()
+            .unwrap_err();
+
+        assert!(
+            error
+                .to_string()
+                .contains("invalid `min_delay`: must not exceed `max_delay` (5s), got 15s"),
+            "{error}"
+        );
+
+        Ok(())
+    });
+}
+
 #[test]
 fn no_backoff() {
     figment::Jail::expect_with(|jail| {
@@ -243,3 +470,273 @@ fn no_backoff() {
         Ok(())
     });
 }
+
+#[test]
+fn retry_budget_with_custom_values() {
+    #[derive(Debug, serde::Deserialize, PartialEq)]
+    struct Config {
+        retry_budget: RetryBudgetConfig,
+    }
+
+    figment::Jail::expect_with(|jail| {
+        jail.create_file(
+            CONFIG_TOML_PATH,
+            r#"
+                [retry_budget]
+                retry_ratio = 0.1
+                min_retries_per_second = 5
+                ttl = "30 s"
+            "#,
+        )?;
+
+        let config = figment::Figment::new()
+            .merge(Data::<Toml>::file(CONFIG_TOML_PATH))
+            .extract::<Config>()?;
+
+        assert_eq!(
+            config,
+            Config {
+                retry_budget: RetryBudgetConfig {
+                    retry_ratio: 0.1,
+                    min_retries_per_second: 5,
+                    ttl: Duration::from_secs(30),
+                }
+            }
+        );
+
+        Ok(())
+    });
+}
+
+#[test]
+fn circuit_breaker_with_custom_values() {
+    #[derive(Debug, serde::Deserialize, PartialEq)]
+    struct Config {
+        circuit_breaker: CircuitBreakerConfig,
+    }
+
+    figment::Jail::expect_with(|jail| {
+        jail.create_file(
+            CONFIG_TOML_PATH,
+            r#"
+                [circuit_breaker]
+                failure_rate_threshold = 0.25
+                window_size = 20
+                half_open_probes = 3
+
+                [circuit_breaker.open_cooldown]
+                strategy = "Constant"
+                delay = "10 s"
+                max_retries = 1
+                jitter_enabled = false
+            "#,
+        )?;
+
+        let config = figment::Figment::new()
+            .merge(Data::<Toml>::file(CONFIG_TOML_PATH))
+            .extract::<Config>()?;
+
+        assert_eq!(
+            config,
+            Config {
+                circuit_breaker: CircuitBreakerConfig {
+                    failure_rate_threshold: 0.25,
+                    window_size: 20,
+                    open_cooldown: BackoffConfig::Constant(
+                        ConstantBackoffConfig::new()
+                            .delay(Duration::from_secs(10))
+                            .max_retries(1)
+                            .jitter(Jitter::Disabled)
+                    ),
+                    half_open_probes: 3,
+                }
+            }
+        );
+
+        Ok(())
+    });
+}
+
+#[test]
+fn strict_backoff_rejects_unknown_keys() {
+    #[derive(Debug, serde::Deserialize, PartialEq)]
+    struct Config {
+        #[serde(deserialize_with = "BackoffConfig::deserialize_strict")]
+        backoff: BackoffConfig,
+    }
+
+    figment::Jail::expect_with(|jail| {
+        jail.create_file(
+            CONFIG_TOML_PATH,
+            r#"
+                [backoff]
+                strategy = "Exponential"
+                initial_delay = "1 s"
+                max_retry = 3
+            "#,
+        )?;
+
+        let error = figment::Figment::new()
+            .merge(Data::<Toml>::file(CONFIG_TOML_PATH))
+            .extract::<Config>()
+            .unwrap_err();
+
+        assert!(error.to_string().contains(
+            "unknown key `max_retry` for strategy `Exponential`, did you mean `max_retries`?"
+        ));
+
+        Ok(())
+    });
+}
+
+#[test]
+fn checked_backoff_collects_unknown_keys() {
+    #[derive(Debug, serde::Deserialize, PartialEq)]
+    struct Config {
+        backoff: CheckedBackoffConfig,
+    }
+
+    figment::Jail::expect_with(|jail| {
+        jail.create_file(
+            CONFIG_TOML_PATH,
+            r#"
+                [backoff]
+                strategy = "Constant"
+                delay = "1 s"
+                max_delay = "10 s"
+            "#,
+        )?;
+
+        let config = figment::Figment::new()
+            .merge(Data::<Toml>::file(CONFIG_TOML_PATH))
+            .extract::<Config>()?;
+
+        assert_eq!(
+            config.backoff.config,
+            BackoffConfig::Constant(ConstantBackoffConfig::new().delay(Duration::from_secs(1)))
+        );
+        assert_eq!(
+            config.backoff.unknown_keys,
+            vec![UnknownKey {
+                key: "max_delay".to_string(),
+                strategy: "Constant",
+                suggestion: None,
+            }]
+        );
+
+        Ok(())
+    });
+}
+
+#[test]
+fn checked_backoff_migrates_legacy_keys() {
+    #[derive(Debug, serde::Deserialize, PartialEq)]
+    struct Config {
+        backoff: CheckedBackoffConfig,
+    }
+
+    figment::Jail::expect_with(|jail| {
+        jail.create_file(
+            CONFIG_TOML_PATH,
+            r#"
+                [backoff]
+                version = 1
+                strategy = "Exponential"
+                jitter_enabled = true
+                jitter_seed = 1337
+            "#,
+        )?;
+
+        let config = figment::Figment::new()
+            .merge(Data::<Toml>::file(CONFIG_TOML_PATH))
+            .extract::<Config>()?;
+
+        assert_eq!(
+            config.backoff.config,
+            BackoffConfig::Exponential(
+                ExponentialBackoffConfig::new().jitter(Jitter::Seeded(1337))
+            )
+        );
+        assert_eq!(
+            config
+                .backoff
+                .deprecations
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>(),
+            [
+                "`version` is deprecated since schema version 2, upgrade from version 1 to 2",
+                "`jitter_enabled` is deprecated since schema version 2, use `jitter = 1337` instead",
+                "`jitter_seed` is deprecated since schema version 2, use `jitter = 1337` instead",
+            ]
+        );
+        assert_eq!(config.backoff.unknown_keys, vec![]);
+
+        Ok(())
+    });
+}
+
+#[test]
+fn strategy_aliases_and_lowercase_names() {
+    figment::Jail::expect_with(|jail| {
+        for (strategy, expected) in [
+            (
+                "exponential",
+                BackoffConfig::Exponential(Default::default()),
+            ),
+            ("Exp", BackoffConfig::Exponential(Default::default())),
+            ("FIB", BackoffConfig::Fibonacci(Default::default())),
+            ("fixed", BackoffConfig::Constant(Default::default())),
+            ("adaptive", BackoffConfig::Adaptive(Default::default())),
+            ("none", BackoffConfig::NoBackoff),
+            ("disabled", BackoffConfig::NoBackoff),
+        ] {
+            jail.create_file(
+                CONFIG_TOML_PATH,
+                &format!(
+                    r#"
+                        [backoff]
+                        strategy = "{strategy}"
+                    "#
+                ),
+            )?;
+
+            let config = figment::Figment::new()
+                .merge(Data::<Toml>::file(CONFIG_TOML_PATH))
+                .extract::<Config>()?;
+
+            assert_eq!(config, Config { backoff: expected }, "{strategy}");
+        }
+
+        Ok(())
+    });
+}
+
+#[test]
+fn scalar_shorthands() {
+    figment::Jail::expect_with(|jail| {
+        for (backoff, expected) in [
+            (r#""none""#, BackoffConfig::NoBackoff),
+            (
+                r#""500ms""#,
+                BackoffConfig::Constant(
+                    ConstantBackoffConfig::new().delay(Duration::from_millis(500)),
+                ),
+            ),
+            (
+                "3",
+                BackoffConfig::Constant(ConstantBackoffConfig::new().max_retries(3)),
+            ),
+        ] {
+            jail.create_file(CONFIG_TOML_PATH, &format!("backoff = {backoff}"))?;
+
+            let config = figment::Figment::new()
+                .merge(Data::<Toml>::file(CONFIG_TOML_PATH))
+                .extract::<Config>()?;
+
+            assert_eq!(config, Config { backoff: expected }, "{backoff}");
+        }
+
+        Ok(())
+    });
+}
@@ -0,0 +1,191 @@
+#![cfg(feature = "watch")]
+#![allow(clippy::result_large_err)]
+
+use backoff_config::*;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+const CONFIG_TOML_PATH: &str = "config.toml";
+const CONFIG_JSON_PATH: &str = "config.json";
+
+fn constant(delay: Duration) -> BackoffConfig {
+    BackoffConfig::Constant(ConstantBackoffConfig::new().delay(delay))
+}
+
+#[test]
+fn reloads_toml_file() {
+    figment::Jail::expect_with(|jail| {
+        jail.create_file(
+            CONFIG_TOML_PATH,
+            r#"
+                [backoff]
+                strategy = "Constant"
+                delay = "1s"
+            "#,
+        )?;
+
+        let shared = SharedBackoffConfig::new(BackoffConfig::NoBackoff);
+        let mut watcher = BackoffConfigWatcher::new(CONFIG_TOML_PATH, shared.clone());
+
+        assert!(watcher.reload().unwrap());
+        assert_eq!(shared.load(), constant(Duration::from_secs(1)));
+
+        assert!(!watcher.reload().unwrap());
+
+        jail.create_file(
+            CONFIG_TOML_PATH,
+            r#"
+                [backoff]
+                strategy = "Constant"
+                delay = "2s"
+            "#,
+        )?;
+
+        assert!(watcher.reload().unwrap());
+        assert_eq!(shared.load(), constant(Duration::from_secs(2)));
+
+        Ok(())
+    });
+}
+
+#[test]
+fn reloads_nested_json_section() {
+    figment::Jail::expect_with(|jail| {
+        jail.create_file(
+            CONFIG_JSON_PATH,
+            r#"{ "client": { "retry": { "strategy": "Constant", "delay": "3s" } } }"#,
+        )?;
+
+        let shared = SharedBackoffConfig::new(BackoffConfig::NoBackoff);
+        let mut watcher =
+            BackoffConfigWatcher::new(CONFIG_JSON_PATH, shared.clone()).section("client.retry");
+
+        assert!(watcher.reload().unwrap());
+        assert_eq!(shared.load(), constant(Duration::from_secs(3)));
+
+        Ok(())
+    });
+}
+
+#[test]
+fn bad_edits_keep_good_config() {
+    figment::Jail::expect_with(|jail| {
+        let good = constant(Duration::from_secs(1));
+        let shared = SharedBackoffConfig::new(good);
+        let mut watcher = BackoffConfigWatcher::new(CONFIG_TOML_PATH, shared.clone());
+
+        assert!(matches!(watcher.reload(), Err(ReloadError::Io(_))));
+
+        jail.create_file(CONFIG_TOML_PATH, "[backoff")?;
+        assert!(matches!(watcher.reload(), Err(ReloadError::Parse(_))));
+
+        jail.create_file(CONFIG_TOML_PATH, "[other]")?;
+        assert!(matches!(
+            watcher.reload(),
+            Err(ReloadError::MissingSection(section)) if section == "backoff"
+        ));
+
+        jail.create_file(
+            CONFIG_TOML_PATH,
+            r#"
+                [backoff]
+                strategy = "Exponential"
+                factor = 0.5
+            "#,
+        )?;
+        assert!(matches!(
+            watcher.reload(),
+            Err(ReloadError::Invalid(ValidationError {
+                field: "factor",
+                ..
+            }))
+        ));
+
+        assert_eq!(shared.load(), good);
+
+        Ok(())
+    });
+}
+
+#[test]
+fn rejects_unsupported_format() {
+    figment::Jail::expect_with(|jail| {
+        jail.create_file("config.ini", "")?;
+
+        let shared = SharedBackoffConfig::new(BackoffConfig::NoBackoff);
+        let mut watcher = BackoffConfigWatcher::new("config.ini", shared);
+
+        assert!(matches!(
+            watcher.reload(),
+            Err(ReloadError::UnsupportedFormat(_))
+        ));
+
+        Ok(())
+    });
+}
+
+#[test]
+fn spawned_watcher_reloads_changes_and_reports_errors() {
+    figment::Jail::expect_with(|jail| {
+        jail.create_file(
+            CONFIG_TOML_PATH,
+            r#"
+                [backoff]
+                strategy = "Constant"
+                delay = "1s"
+            "#,
+        )?;
+
+        let shared = SharedBackoffConfig::new(BackoffConfig::NoBackoff);
+        let changes = shared.subscribe();
+        let errors = Arc::new(Mutex::new(Vec::new()));
+
+        let handle = BackoffConfigWatcher::new(jail.directory().join(CONFIG_TOML_PATH), shared)
+            .poll_interval(Duration::from_millis(10))
+            .on_error({
+                let errors = errors.clone();
+                move |error| errors.lock().unwrap().push(error.to_string())
+            })
+            .spawn()
+            .unwrap();
+
+        assert_eq!(
+            changes.recv_timeout(Duration::from_secs(5)).unwrap(),
+            constant(Duration::from_secs(1))
+        );
+
+        jail.create_file(CONFIG_TOML_PATH, "[backoff")?;
+        let deadline = Instant::now() + Duration::from_secs(5);
+        while errors.lock().unwrap().is_empty() && Instant::now() < deadline {
+            std::thread::sleep(Duration::from_millis(10));
+        }
+        assert!(
+            !errors.lock().unwrap().is_empty(),
+            "no reload error reported within 5s"
+        );
+
+        jail.create_file(
+            CONFIG_TOML_PATH,
+            r#"
+                [backoff]
+                strategy = "NoBackoff"
+            "#,
+        )?;
+        assert_eq!(
+            changes.recv_timeout(Duration::from_secs(5)).unwrap(),
+            BackoffConfig::NoBackoff
+        );
+
+        drop(handle);
+
+        assert!(
+            errors
+                .lock()
+                .unwrap()
+                .iter()
+                .any(|error| error.starts_with("failed to parse backoff config"))
+        );
+
+        Ok(())
+    });
+}